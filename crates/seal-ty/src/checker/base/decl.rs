@@ -1,18 +1,17 @@
-use swc_common::Spanned;
-use swc_ecma_ast::{
-	ClassDecl, Decl, FnDecl, Pat, TsInterfaceDecl, TsPropertySignature, VarDeclKind,
-};
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{ClassDecl, Decl, Expr, FnDecl, Pat, VarDeclKind};
 
 use crate::{
-	TyKind,
+	Ty, TyKind,
 	checker::{class::ClassChecker, errors::ErrorKind, function::FunctionChecker},
-	sir::{Def, Value},
+	kind::{Generic, Object},
+	sir::{Def, Local, Value},
 	symbol::Symbol,
 };
 
 use super::BaseChecker;
 
-impl BaseChecker<'_> {
+impl<'tcx> BaseChecker<'tcx> {
 	pub fn check_decl(&self, decl: &Decl) {
 		match decl {
 			Decl::Var(var) => {
@@ -31,7 +30,15 @@ impl BaseChecker<'_> {
 				for var_declarator in &var.decls {
 					let binding = match &var_declarator.name {
 						Pat::Ident(ident) => ident,
-						_ => todo!("{:#?}", var_declarator.name),
+						pat => {
+							self.check_destructuring_decl(
+								pat,
+								var_declarator.init.as_deref(),
+								var.kind,
+								var_declarator.span,
+							);
+							continue;
+						}
 					};
 
 					let name = Symbol::new(binding.to_id());
@@ -90,7 +97,8 @@ impl BaseChecker<'_> {
 						// For const declarations, use the narrower actual type if it satisfies the annotation
 						// For let declarations, use the annotation type to allow future assignment of compatible types
 						let final_type = if is_const {
-							actual.ty // Use the specific literal type for const
+							// Use the specific literal type for const
+							self.narrow_to_initializer(binding_ty, actual.ty)
 						} else {
 							binding_ty // Use the annotation type for let (allows broader assignments)
 						};
@@ -112,29 +120,12 @@ impl BaseChecker<'_> {
 				ident, function, ..
 			}) => {
 				let name = Symbol::new(ident.to_id());
+				let type_params = self.bind_type_params(function.type_params.as_deref());
 
-				let mut params = vec![];
-				for param in &function.params {
-					match &param.pat {
-						Pat::Ident(ident) => {
-							let name = Symbol::new(ident.to_id());
-
-							let ty = match &ident.type_ann {
-								Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
-								None => {
-									self.add_error_with_span(
-										ErrorKind::ParamMissingTypeAnn,
-										ident.span,
-									);
-									self.constants.err
-								}
-							};
-
-							params.push((name, ty));
-						}
-						_ => todo!("{:#?}", param),
-					}
-				}
+				let params = self
+					.build_params(function.params.iter().map(|param| &param.pat), &|pat| {
+						self.param_missing_type_ann(pat)
+					});
 
 				let ret = match &function.return_type {
 					Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
@@ -144,14 +135,27 @@ impl BaseChecker<'_> {
 					}
 				};
 
+				if function.body.is_none() {
+					let signature = self
+						.tcx
+						.new_function(params.into_function(ret).with_type_params(type_params));
+					self.add_overload(name, signature, ident.span);
+					return;
+				}
+
 				let checker = FunctionChecker::new(self.tcx, params, ret);
+				checker.set_type_param_bindings(&type_params);
+				checker.set_class_scope(self.nested_class_scope());
 				let result = checker.check_function(function);
 
 				for error in result.errors {
 					self.add_error_with_span(error.kind, error.span);
 				}
 
-				let ty = self.tcx.new_function(result.ty);
+				let ty = self
+					.tcx
+					.new_function(result.ty.with_type_params(type_params));
+				let ty = self.merge_overloads(&name, ty);
 
 				self.set_binding(
 					&name,
@@ -163,14 +167,14 @@ impl BaseChecker<'_> {
 			Decl::Class(ClassDecl { ident, class, .. }) => {
 				let name = Symbol::new(ident.to_id());
 
-				let checker = ClassChecker::new_with_parent(self, &name);
+				let checker = ClassChecker::new_with_parent(self, &name, ident.span);
 				let result = checker.check_class(class);
 
 				for error in result.errors {
 					self.add_error_with_span(error.kind, error.span);
 				}
 
-				let ty = self.tcx.new_class(result.ty);
+				let ty = result.ty;
 
 				self.set_binding(
 					&name,
@@ -182,50 +186,119 @@ impl BaseChecker<'_> {
 			Decl::TsTypeAlias(type_alias) => {
 				// Type alias declarations: type Status = "loading" | "success" | "error"
 				let name = Symbol::new(type_alias.id.to_id());
+				let type_params = self.bind_type_params(type_alias.type_params.as_deref());
 				let aliased_type = self.build_ts_type(&type_alias.type_ann);
 
+				// Generic aliases are instantiated on reference (e.g. Pair<number>)
+				let aliased_type = if type_params.is_empty() {
+					aliased_type
+				} else {
+					self.tcx
+						.new_generic(Generic::new(name.clone(), type_params, aliased_type))
+				};
+
 				// Store the type alias as a binding so it can be referenced by name
 				self.set_binding(&name, None, aliased_type, false);
 			}
-			Decl::TsInterface(interface_decl) => {
-				let TsInterfaceDecl { id, body, .. } = interface_decl.as_ref();
-				// Interface declarations
-				let name = Symbol::new(id.to_id());
-				let mut fields = std::collections::BTreeMap::new();
-
-				// Process interface members
-				for member in &body.body {
-					match member {
-						swc_ecma_ast::TsTypeElement::TsPropertySignature(TsPropertySignature {
-							key,
-							type_ann,
-							..
-						}) => {
-							if let Some(ident) = key.as_ident() {
-								let prop_name = ident.sym.clone();
-								let prop_type = if let Some(type_ann) = type_ann {
-									self.build_ts_type(&type_ann.type_ann)
-								} else {
-									self.constants.unknown
-								};
-								fields.insert(prop_name, prop_type);
+			Decl::TsInterface(interface_decl) => self.check_interface(interface_decl),
+			Decl::TsEnum(enum_decl) => self.check_enum(enum_decl),
+			_ => todo!("{:#?}", decl),
+		}
+	}
+
+	/// An object literal narrowing an annotated const keeps the optional members it omits
+	/// (readable as 'T | undefined'), the readonly members and the index signatures of the annotation
+	fn narrow_to_initializer(&self, declared: Ty<'tcx>, actual: Ty<'tcx>) -> Ty<'tcx> {
+		// arrays and tuples keep their declared shape (e.g. 'readonly number[]')
+		if matches!(declared.kind(), TyKind::Array(_) | TyKind::Tuple(_)) {
+			return declared;
+		}
+		let TyKind::Object(obj) = actual.kind() else {
+			return actual;
+		};
+		let (fields, optional, readonly, index) = match declared.kind() {
+			TyKind::Object(declared) => (
+				declared.fields(),
+				declared.optional(),
+				declared.readonly(),
+				declared.index(),
+			),
+			TyKind::Interface(declared) => (
+				declared.fields(),
+				declared.optional(),
+				declared.readonly(),
+				declared.index(),
+			),
+			_ => return actual,
+		};
+
+		let mut narrowed = Object::new(obj.fields().clone())
+			.with_optional(obj.optional().clone())
+			.with_readonly(readonly.clone())
+			.with_index(*index);
+		for key in optional {
+			if !narrowed.fields.contains_key(key) {
+				narrowed.fields.insert(key.clone(), fields[key]);
+				narrowed.optional.insert(key.clone());
+			}
+		}
+
+		self.tcx.new_object(narrowed)
+	}
+
+	/// `const { a, b: [c] } = init`: binds each name of the pattern to its part of the initializer,
+	/// or of the annotation if there is one
+	fn check_destructuring_decl(
+		&self,
+		pat: &Pat,
+		init: Option<&Expr>,
+		kind: VarDeclKind,
+		span: Span,
+	) {
+		let is_const = kind == VarDeclKind::Const;
+		let annotation =
+			Self::pat_type_ann(pat).map(|type_ann| self.build_ts_type(&type_ann.type_ann));
+
+		let value = match init {
+			Some(init) => {
+				let actual = self.check_expr(init, annotation);
+
+				match annotation {
+					Some(annotation) => {
+						if !self.satisfies(annotation, actual.ty) {
+							match actual.ty.kind() {
+								TyKind::Object(_) => {
+									self.raise_object_type_error(annotation, actual.ty, init.span())
+								}
+								_ => self.raise_type_error(annotation, actual.ty, init.span()),
 							}
 						}
-						_ => {
-							// Other interface members like methods, index signatures etc
-							// For now, we'll skip them
+
+						Local {
+							id: actual.id,
+							ty: annotation,
 						}
 					}
+					None => actual,
 				}
+			}
+			None => {
+				self.add_error_with_span(ErrorKind::DestructuringMissingInit, span);
+				self.add_local(annotation.unwrap_or(self.constants.err), Value::Err)
+			}
+		};
 
-				// Create the interface type
-				let interface = std::rc::Rc::new(crate::kind::Interface::new(name.clone(), fields));
-				let interface_type = self.tcx.new_interface(interface);
+		self.destructure(pat, value, &mut |ident, local| {
+			let name = Symbol::new(ident.to_id());
 
-				// Store it as a binding
-				self.set_binding(&name, None, interface_type, false);
+			match kind {
+				// NOTE: like other 'var' declarations, make every reference an error
+				VarDeclKind::Var => self.set_binding(&name, None, self.constants.never, false),
+				_ if is_const || annotation.is_some() => {
+					self.set_binding(&name, Some(local), local.ty, !is_const)
+				}
+				_ => self.set_binding(&name, Some(local), self.widen(local.ty), true),
 			}
-			_ => todo!("{:#?}", decl),
-		}
+		});
 	}
 }
@@ -0,0 +1,248 @@
+use std::{collections::BTreeSet, rc::Rc};
+
+use swc_atoms::Atom;
+use swc_common::Spanned;
+use swc_ecma_ast::{
+	BinExpr, BinaryOp, Expr, Lit, MemberExpr, MemberProp, Number, Str, Tpl, TsEnumDecl,
+	TsEnumMemberId, UnaryExpr, UnaryOp,
+};
+
+use crate::{
+	Ty, TyKind,
+	checker::errors::ErrorKind,
+	kind::{Enum, EnumMember, NumberLit},
+	sir::{Object, Value},
+	symbol::Symbol,
+};
+
+use super::BaseChecker;
+
+/// The value of a constant enum member
+#[derive(Debug, Clone, PartialEq)]
+enum EnumValue {
+	Number(f64),
+	String(Atom),
+}
+
+impl EnumValue {
+	/// Converts to a string like JavaScript does in a concatenation
+	fn to_atom(&self) -> Atom {
+		match self {
+			EnumValue::Number(value) if *value == 0.0 => Atom::new("0"),
+			EnumValue::Number(value) => Atom::new(NumberLit::new(*value).to_string()),
+			EnumValue::String(value) => value.clone(),
+		}
+	}
+}
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Declares the members of an enum, numbered from 0 or after the previous numeric member,
+	/// and binds its name to the enum object
+	pub fn check_enum(&self, decl: &TsEnumDecl) {
+		let name = Symbol::new(decl.id.to_id());
+
+		let mut values: Vec<(Atom, Option<EnumValue>)> = vec![];
+		// the value of the next member without an initializer, if it can be auto-incremented
+		let mut next = Some(0.0);
+
+		for member in &decl.members {
+			let key = match &member.id {
+				TsEnumMemberId::Ident(ident) => ident.sym.clone(),
+				TsEnumMemberId::Str(Str { value, .. }) => value.clone(),
+			};
+
+			let value = match &member.init {
+				Some(init) => {
+					let value = self.eval_enum_initializer(init, &decl.id.sym, &values);
+
+					if value.is_none() {
+						if decl.is_const {
+							self.add_error_with_span(
+								ErrorKind::ConstEnumMemberInitializer,
+								init.span(),
+							);
+						} else {
+							// a computed member must be a number
+							let actual = self.check_expr(init, None);
+							if !self.satisfies(self.constants.number, actual.ty) {
+								self.raise_type_error(
+									self.constants.number,
+									actual.ty,
+									init.span(),
+								);
+							}
+						}
+					}
+
+					value
+				}
+				None => match next {
+					Some(next) => Some(EnumValue::Number(next)),
+					None => {
+						self.add_error_with_span(
+							ErrorKind::EnumMemberMustHaveInitializer,
+							member.span,
+						);
+						None
+					}
+				},
+			};
+
+			next = match value {
+				Some(EnumValue::Number(value)) => Some(value + 1.0),
+				_ => None,
+			};
+			values.push((key, value));
+		}
+
+		let mut obj = Object::new();
+		let members = values
+			.into_iter()
+			.map(|(key, value)| {
+				let (ty, value) = match value {
+					Some(EnumValue::Number(value)) => (
+						self.tcx.new_const_number(value),
+						Value::Number(NumberLit::new(value)),
+					),
+					Some(EnumValue::String(value)) => {
+						(self.tcx.new_const_string(value.clone()), Value::Str(value))
+					}
+					None => (self.constants.number, Value::Err),
+				};
+
+				obj.fields.push((key.clone(), self.add_local(ty, value).id));
+				(key, ty)
+			})
+			.collect();
+
+		let enum_ = Rc::new(Enum::new(name.clone(), members).with_const(decl.is_const));
+		let ty = self.tcx.new_enum_object(enum_);
+
+		self.set_binding(&name, Some(self.add_local(ty, Value::Obj(obj))), ty, false);
+	}
+
+	/// The member types of an enum, e.g. to narrow a value of the enum type
+	pub fn enum_members(&self, enum_: &Rc<Enum<'tcx>>) -> BTreeSet<Ty<'tcx>> {
+		enum_
+			.members()
+			.iter()
+			.map(|(key, _)| {
+				self.tcx
+					.new_enum_member(EnumMember::new(enum_.clone(), key.clone()))
+			})
+			.collect()
+	}
+
+	/// Evaluates a constant enum expression: literals, previous members and members of other enums,
+	/// combined with arithmetic, bitwise operators and string concatenation
+	fn eval_enum_initializer(
+		&self,
+		expr: &Expr,
+		enum_name: &Atom,
+		values: &[(Atom, Option<EnumValue>)],
+	) -> Option<EnumValue> {
+		let eval = |expr: &Expr| self.eval_enum_initializer(expr, enum_name, values);
+		let member = |key: &Atom| {
+			values
+				.iter()
+				.find(|(name, _)| name == key)
+				.and_then(|(_, value)| value.clone())
+		};
+
+		match expr {
+			Expr::Lit(Lit::Num(Number { value, .. })) => Some(EnumValue::Number(*value)),
+			Expr::Lit(Lit::Str(Str { value, .. })) => Some(EnumValue::String(value.clone())),
+			Expr::Tpl(Tpl { exprs, quasis, .. }) => {
+				let mut text = quasis[0].cooked.clone()?.to_string();
+				for (expr, quasi) in exprs.iter().zip(&quasis[1..]) {
+					text.push_str(&eval(expr)?.to_atom());
+					text.push_str(&quasi.cooked.clone()?);
+				}
+				Some(EnumValue::String(Atom::new(text)))
+			}
+			Expr::Paren(paren) => eval(&paren.expr),
+			Expr::Ident(ident) => member(&ident.sym),
+			Expr::Member(MemberExpr { obj, prop, .. }) => {
+				let Expr::Ident(obj) = obj.as_ref() else {
+					return None;
+				};
+				let key = match prop {
+					MemberProp::Ident(ident) => ident.sym.clone(),
+					MemberProp::Computed(computed) => match computed.expr.as_ref() {
+						Expr::Lit(Lit::Str(Str { value, .. })) => value.clone(),
+						_ => return None,
+					},
+					_ => return None,
+				};
+
+				if &obj.sym == enum_name {
+					return member(&key);
+				}
+
+				// a member of another enum
+				let binding = self.get_binding(&Symbol::new(obj.to_id()))?;
+				let TyKind::EnumObject(enum_) = binding.ty.kind() else {
+					return None;
+				};
+				match enum_.get_member(&key)?.kind() {
+					TyKind::Number(Some(value)) => Some(EnumValue::Number(value.value())),
+					TyKind::String(Some(value)) => Some(EnumValue::String(value.clone())),
+					_ => None,
+				}
+			}
+			Expr::Unary(UnaryExpr { op, arg, .. }) => {
+				let EnumValue::Number(value) = eval(arg)? else {
+					return None;
+				};
+
+				Some(EnumValue::Number(match op {
+					UnaryOp::Plus => value,
+					UnaryOp::Minus => -value,
+					UnaryOp::Tilde => !to_int32(value) as f64,
+					_ => return None,
+				}))
+			}
+			Expr::Bin(BinExpr {
+				op, left, right, ..
+			}) => match (eval(left)?, eval(right)?) {
+				(EnumValue::Number(left), EnumValue::Number(right)) => {
+					Some(EnumValue::Number(match op {
+						BinaryOp::Add => left + right,
+						BinaryOp::Sub => left - right,
+						BinaryOp::Mul => left * right,
+						BinaryOp::Div => left / right,
+						BinaryOp::Mod => left % right,
+						BinaryOp::Exp => left.powf(right),
+						BinaryOp::BitOr => (to_int32(left) | to_int32(right)) as f64,
+						BinaryOp::BitAnd => (to_int32(left) & to_int32(right)) as f64,
+						BinaryOp::BitXor => (to_int32(left) ^ to_int32(right)) as f64,
+						BinaryOp::LShift => {
+							to_int32(left).wrapping_shl(to_int32(right) as u32) as f64
+						}
+						BinaryOp::RShift => {
+							to_int32(left).wrapping_shr(to_int32(right) as u32) as f64
+						}
+						BinaryOp::ZeroFillRShift => {
+							(to_int32(left) as u32).wrapping_shr(to_int32(right) as u32) as f64
+						}
+						_ => return None,
+					}))
+				}
+				(left, right) if *op == BinaryOp::Add => Some(EnumValue::String(Atom::new(
+					format!("{}{}", left.to_atom(), right.to_atom()),
+				))),
+				_ => None,
+			},
+			_ => None,
+		}
+	}
+}
+
+/// JavaScript's conversion of a number operand of a bitwise operator
+fn to_int32(value: f64) -> i32 {
+	if !value.is_finite() {
+		return 0;
+	}
+
+	value.trunc().rem_euclid(4294967296.0) as u32 as i32
+}
@@ -1,20 +1,25 @@
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeSet, HashMap};
 
-use swc_common::Spanned;
+use swc_common::{Span, Spanned, SyntaxContext};
 use swc_ecma_ast::{
-	AssignExpr, AssignTarget, BinExpr, BinaryOp, BlockStmtOrExpr, Bool, CallExpr, Callee, Expr,
-	ExprOrSpread, Lit, MemberExpr, MemberProp, NewExpr, Number, ObjectLit, Pat, Prop, PropOrSpread,
-	SeqExpr, SimpleAssignTarget, Str, TsConstAssertion, TsSatisfiesExpr, UnaryOp,
+	ArrayPat, AssignExpr, AssignTarget, AssignTargetPat, BigInt, BinExpr, BinaryOp, BindingIdent,
+	BlockStmtOrExpr, Bool, CallExpr, Callee, Expr, Lit, MemberExpr, MemberProp, NewExpr, Number,
+	ObjectLit, ObjectPat, Pat, SeqExpr, SimpleAssignTarget, Str, ThisExpr, TsConstAssertion,
+	TsSatisfiesExpr, UnaryOp,
 };
 
 use crate::{
 	Ty, TyKind,
 	checker::{errors::ErrorKind, function::FunctionChecker},
+	kind::{BigIntLit, EnumMember, Function, NumberLit, TypeParameter, Visibility},
 	sir::{Local, Value},
 	symbol::Symbol,
 };
 
-use super::BaseChecker;
+use super::{
+	BaseChecker, Binding,
+	spread::{Args, Element},
+};
 
 impl<'tcx> BaseChecker<'tcx> {
 	pub fn check_expr(&self, expr: &Expr, expected_ty: Option<Ty<'tcx>>) -> Local<'tcx> {
@@ -69,44 +74,54 @@ impl<'tcx> BaseChecker<'tcx> {
 						}
 						SimpleAssignTarget::Member(member) => {
 							// Handle member assignment: obj.prop = value
-							let obj = self.check_expr(&member.obj, None);
+							let obj = self.check_member_object(&member.obj, None);
 							let value = self.check_expr(right, expected_ty);
 
 							match &member.prop {
-								MemberProp::Ident(ident) => {
-									let key = ident.sym.clone();
+								MemberProp::Ident(_) | MemberProp::PrivateName(_) => {
+									let (key, key_span) = Self::member_prop_key(&member.prop);
+									let target = self.apparent_receiver(obj.ty);
+									self.check_member_visibility(target, &key, key_span);
 
 									// Check if the property exists and get its expected type
-									match obj.ty.kind() {
-										TyKind::Object(obj_ty) => {
-											if let Some(prop_ty) = obj_ty.get_prop(&key) {
-												// Property exists, check type compatibility
-												if !self.satisfies(prop_ty, value.ty) {
-													self.raise_type_error(
-														prop_ty,
-														value.ty,
-														right.span(),
-													);
-												}
-											} else {
-												// Property doesn't exist
-												self.add_error_with_span(
-													ErrorKind::PropertyDoesNotExist(
-														obj.ty,
-														key.clone(),
-													),
-													ident.span,
+									// (an optional property also accepts 'undefined')
+									let prop_ty = match target.kind() {
+										TyKind::Object(_)
+										| TyKind::Interface(_)
+										| TyKind::Class(_)
+										| TyKind::EnumObject(_) => self.lookup_property(target, &key),
+										// Trying to assign to a property of a non-object type
+										_ => None,
+									};
+
+									match prop_ty {
+										Some(_)
+											if self.is_readonly_property(target, &key)
+												&& !self.is_constructing(target) =>
+										{
+											self.add_error_with_span(
+												ErrorKind::CannotAssignToReadOnlyProperty(key),
+												key_span,
+											);
+										}
+										Some(prop_ty) => {
+											// Property exists, check type compatibility
+											if !self.satisfies(prop_ty, value.ty) {
+												self.raise_type_error(
+													prop_ty,
+													value.ty,
+													right.span(),
 												);
 											}
 										}
-										_ => {
-											// Trying to assign to a property of a non-object type
+										None => {
+											// Property doesn't exist
 											self.add_error_with_span(
 												ErrorKind::PropertyDoesNotExist(
-													obj.ty,
+													target,
 													key.clone(),
 												),
-												ident.span,
+												key_span,
 											);
 										}
 									}
@@ -119,6 +134,42 @@ impl<'tcx> BaseChecker<'tcx> {
 									let index = self.check_expr(&computed.expr, None);
 
 									match obj.ty.kind() {
+										TyKind::Array(array) if array.readonly => {
+											self.add_error_with_span(
+												ErrorKind::ReadonlyIndexSignature(obj.ty),
+												member.span,
+											);
+										}
+										TyKind::Tuple(tuple) => match index.ty.kind() {
+											TyKind::Number(Some(value)) if tuple.readonly => {
+												self.add_error_with_span(
+													ErrorKind::CannotAssignToReadOnlyProperty(
+														swc_atoms::Atom::new(value.to_string()),
+													),
+													member.span,
+												);
+											}
+											_ if tuple.readonly => {
+												self.add_error_with_span(
+													ErrorKind::ReadonlyIndexSignature(obj.ty),
+													member.span,
+												);
+											}
+											TyKind::Number(Some(position)) => {
+												if let Some(element) = position
+													.as_index()
+													.and_then(|index| tuple.elements.get(index))
+													&& !self.satisfies(*element, value.ty)
+												{
+													self.raise_type_error(
+														*element,
+														value.ty,
+														right.span(),
+													);
+												}
+											}
+											_ => {}
+										},
 										TyKind::Array(array) => {
 											if let Some(name) = member.obj.as_ident()
 												&& matches!(array.element.kind(), TyKind::Unknown)
@@ -147,9 +198,43 @@ impl<'tcx> BaseChecker<'tcx> {
 												}
 											}
 										}
-										TyKind::Object(_) => {
-											// Object with computed property - we don't track these precisely
-											// Just return the value without error
+										TyKind::Object(_) | TyKind::Interface(_) => {
+											let key = match index.ty.kind() {
+												TyKind::String(Some(key)) => Some(key.clone()),
+												TyKind::Number(Some(value)) => {
+													Some(swc_atoms::Atom::new(value.to_string()))
+												}
+												_ => None,
+											};
+
+											match self.lookup_computed(obj.ty, index.ty) {
+												Ok(Some(_))
+													if key.as_ref().is_some_and(|key| {
+														self.is_readonly_property(obj.ty, key)
+													}) =>
+												{
+													self.add_error_with_span(
+														ErrorKind::CannotAssignToReadOnlyProperty(
+															key.unwrap(),
+														),
+														computed.span,
+													);
+												}
+												Ok(Some(prop_ty)) => {
+													if !self.satisfies(prop_ty, value.ty) {
+														self.raise_type_error(
+															prop_ty,
+															value.ty,
+															right.span(),
+														);
+													}
+												}
+												// Dynamic key without an index signature - we don't track these precisely
+												Ok(None) => {}
+												Err(error) => {
+													self.add_error_with_span(error, computed.span)
+												}
+											}
 										}
 										_ => {
 											// Can't index into non-array/object types
@@ -159,12 +244,28 @@ impl<'tcx> BaseChecker<'tcx> {
 
 									value
 								}
-								_ => todo!("Private member assignment not implemented"),
 							}
 						}
 						_ => todo!("{:#?}", target),
 					},
-					_ => todo!("{:#?}", left),
+					AssignTarget::Pat(pat) => {
+						let value = self.check_expr(right, expected_ty);
+						let assign = &mut |ident: &BindingIdent, value: Local<'tcx>| {
+							self.assign_destructured(ident, value)
+						};
+
+						match pat {
+							AssignTargetPat::Array(ArrayPat { elems, span, .. }) => {
+								self.destructure_array(elems, value, *span, assign)
+							}
+							AssignTargetPat::Object(ObjectPat { props, span, .. }) => {
+								self.destructure_object(props, value, *span, assign)
+							}
+							AssignTargetPat::Invalid(_) => todo!("{:#?}", pat),
+						}
+
+						value
+					}
 				}
 			}
 			Expr::TsSatisfies(TsSatisfiesExpr { expr, type_ann, .. }) => {
@@ -183,11 +284,15 @@ impl<'tcx> BaseChecker<'tcx> {
 				Lit::Bool(Bool { value, .. }) => {
 					self.add_local(self.tcx.new_const_boolean(*value), Value::Bool(*value))
 				}
-				Lit::Num(Number { value, .. }) => {
+				Lit::Num(Number { value, .. }) => self.add_local(
+					self.tcx.new_const_number(*value),
+					Value::Number(NumberLit::new(*value)),
+				),
+				Lit::BigInt(BigInt { value, .. }) => {
+					let value = BigIntLit::new(&value.to_string());
 					self.add_local(
-						self.tcx.new_const_number(*value as i64),
-						// TODO: float
-						Value::Int(*value as i64),
+						self.tcx.new_const_bigint(value.clone()),
+						Value::BigInt(value),
 					)
 				}
 				Lit::Str(Str { value, .. }) => self.add_local(
@@ -204,6 +309,17 @@ impl<'tcx> BaseChecker<'tcx> {
 				}
 				_ => todo!("{:#?}", lit),
 			},
+			Expr::This(ThisExpr { span }) => match self.get_binding(&Symbol::new_this()) {
+				Some(Binding {
+					ty,
+					current: Some(current),
+					..
+				}) => Local { id: current.id, ty },
+				_ => {
+					self.add_error_with_span(ErrorKind::ThisImplicitlyAny, *span);
+					self.add_local(self.constants.err, Value::Err)
+				}
+			},
 			Expr::Ident(ident) => {
 				let name = Symbol::new(ident.to_id());
 
@@ -213,6 +329,12 @@ impl<'tcx> BaseChecker<'tcx> {
 				}
 
 				if let Some(binding) = self.get_binding(&name) {
+					if let TyKind::EnumObject(enum_) = binding.ty.kind()
+						&& enum_.is_const()
+					{
+						self.add_error_with_span(ErrorKind::ConstEnumOnlyInAccess, ident.span);
+					}
+
 					// TODO: if in closure, this should be Value::Var
 					if let Some(current) = binding.current {
 						Local {
@@ -224,6 +346,13 @@ impl<'tcx> BaseChecker<'tcx> {
 						self.add_error_with_span(ErrorKind::UsedBeforeAssigned(name), ident.span);
 						self.add_local(self.constants.err, Value::Err)
 					}
+				} else if let Some(value) = match ident.sym.as_str() {
+					"NaN" => Some(f64::NAN),
+					"Infinity" => Some(f64::INFINITY),
+					_ => None,
+				} {
+					// global number constants, typed as 'number' like in TypeScript
+					self.add_local(self.constants.number, Value::Number(NumberLit::new(value)))
 				} else {
 					self.add_error_with_span(ErrorKind::CannotFindName(name), ident.span);
 					self.add_local(self.constants.err, Value::Err)
@@ -255,8 +384,18 @@ impl<'tcx> BaseChecker<'tcx> {
 						match value.ty.kind() {
 							TyKind::Number(Some(n)) => {
 								// Preserve literal type: -42 becomes literal type -42
-								self.add_local(self.tcx.new_const_number(-n), Value::Int(-n))
+								self.add_local(
+									self.tcx.new_const_number(-n.value()),
+									Value::Number(-*n),
+								)
 							}
+							TyKind::BigInt(Some(n)) => {
+								self.add_local(self.tcx.new_const_bigint(-n), Value::BigInt(-n))
+							}
+							TyKind::BigInt(None) => self.add_local(
+								self.constants.bigint,
+								Value::Unary(crate::sir::UnaryOp::Minus, value.id),
+							),
 							TyKind::Number(None) => {
 								// Generic number stays generic
 								self.add_local(
@@ -327,6 +466,10 @@ impl<'tcx> BaseChecker<'tcx> {
 							self.constants.number,
 							Value::Binary(crate::sir::BinaryOp::Add, left.id, right.id),
 						),
+						(TyKind::BigInt(_), TyKind::BigInt(_)) => self.add_local(
+							self.constants.bigint,
+							Value::Binary(crate::sir::BinaryOp::Add, left.id, right.id),
+						),
 						(TyKind::String(_), TyKind::String(_)) => self.add_local(
 							self.constants.string,
 							Value::Binary(crate::sir::BinaryOp::Add, left.id, right.id),
@@ -348,6 +491,10 @@ impl<'tcx> BaseChecker<'tcx> {
 							self.constants.number,
 							Value::Binary(crate::sir::BinaryOp::Sub, left.id, right.id),
 						),
+						(TyKind::BigInt(_), TyKind::BigInt(_)) => self.add_local(
+							self.constants.bigint,
+							Value::Binary(crate::sir::BinaryOp::Sub, left.id, right.id),
+						),
 						_ => {
 							self.add_error_with_span(
 								ErrorKind::BinaryOperatorTypeMismatch(
@@ -365,6 +512,10 @@ impl<'tcx> BaseChecker<'tcx> {
 							self.constants.number,
 							Value::Binary(crate::sir::BinaryOp::Mul, left.id, right.id),
 						),
+						(TyKind::BigInt(_), TyKind::BigInt(_)) => self.add_local(
+							self.constants.bigint,
+							Value::Binary(crate::sir::BinaryOp::Mul, left.id, right.id),
+						),
 						_ => {
 							self.add_error_with_span(
 								ErrorKind::BinaryOperatorTypeMismatch(
@@ -382,6 +533,10 @@ impl<'tcx> BaseChecker<'tcx> {
 							self.constants.number,
 							Value::Binary(crate::sir::BinaryOp::Div, left.id, right.id),
 						),
+						(TyKind::BigInt(_), TyKind::BigInt(_)) => self.add_local(
+							self.constants.bigint,
+							Value::Binary(crate::sir::BinaryOp::Div, left.id, right.id),
+						),
 						_ => {
 							self.add_error_with_span(
 								ErrorKind::BinaryOperatorTypeMismatch(
@@ -396,7 +551,11 @@ impl<'tcx> BaseChecker<'tcx> {
 					},
 					// Comparison operators
 					BinaryOp::Lt => match (left.ty.kind(), right.ty.kind()) {
-						(TyKind::Number(_), TyKind::Number(_)) => self.add_local(
+						// bigints and numbers can be compared with each other
+						(
+							TyKind::Number(_) | TyKind::BigInt(_),
+							TyKind::Number(_) | TyKind::BigInt(_),
+						) => self.add_local(
 							self.constants.boolean,
 							Value::Binary(crate::sir::BinaryOp::Lt, left.id, right.id),
 						),
@@ -417,7 +576,11 @@ impl<'tcx> BaseChecker<'tcx> {
 						}
 					},
 					BinaryOp::LtEq => match (left.ty.kind(), right.ty.kind()) {
-						(TyKind::Number(_), TyKind::Number(_)) => self.add_local(
+						// bigints and numbers can be compared with each other
+						(
+							TyKind::Number(_) | TyKind::BigInt(_),
+							TyKind::Number(_) | TyKind::BigInt(_),
+						) => self.add_local(
 							self.constants.boolean,
 							Value::Binary(crate::sir::BinaryOp::LtEq, left.id, right.id),
 						),
@@ -438,7 +601,11 @@ impl<'tcx> BaseChecker<'tcx> {
 						}
 					},
 					BinaryOp::Gt => match (left.ty.kind(), right.ty.kind()) {
-						(TyKind::Number(_), TyKind::Number(_)) => self.add_local(
+						// bigints and numbers can be compared with each other
+						(
+							TyKind::Number(_) | TyKind::BigInt(_),
+							TyKind::Number(_) | TyKind::BigInt(_),
+						) => self.add_local(
 							self.constants.boolean,
 							Value::Binary(crate::sir::BinaryOp::Gt, left.id, right.id),
 						),
@@ -459,7 +626,11 @@ impl<'tcx> BaseChecker<'tcx> {
 						}
 					},
 					BinaryOp::GtEq => match (left.ty.kind(), right.ty.kind()) {
-						(TyKind::Number(_), TyKind::Number(_)) => self.add_local(
+						// bigints and numbers can be compared with each other
+						(
+							TyKind::Number(_) | TyKind::BigInt(_),
+							TyKind::Number(_) | TyKind::BigInt(_),
+						) => self.add_local(
 							self.constants.boolean,
 							Value::Binary(crate::sir::BinaryOp::GtEq, left.id, right.id),
 						),
@@ -494,71 +665,53 @@ impl<'tcx> BaseChecker<'tcx> {
 			Expr::Member(MemberExpr {
 				obj, prop, span, ..
 			}) => {
-				let obj = self.check_expr(obj, expected_ty);
+				let obj = self.check_member_object(obj, expected_ty);
 
 				match &prop {
-					MemberProp::Ident(ident) => {
-						let key = ident.sym.clone();
-						self.handle_property_access(obj, key, *span)
+					MemberProp::Ident(_) | MemberProp::PrivateName(_) => {
+						let (key, key_span) = Self::member_prop_key(prop);
+						self.check_member_visibility(
+							self.apparent_receiver(obj.ty),
+							&key,
+							key_span,
+						);
+
+						// a polymorphic `this` in the type of the member is the type of the object
+						let member = self.handle_property_access(obj, key, *span);
+						let subst = HashMap::from([(Symbol::new_this(), obj.ty)]);
+						Local {
+							id: member.id,
+							ty: self.instantiate(member.ty, &subst),
+						}
 					}
 					MemberProp::Computed(computed) => {
 						// Handle computed property access like arr[0] or obj["key"]
 						let index = self.check_expr(&computed.expr, expected_ty);
 						self.handle_computed_access(obj, index, *span)
 					}
-					_ => todo!("{:#?}", prop),
 				}
 			}
-			Expr::Object(ObjectLit { props, .. }) => {
-				let mut obj_ty = crate::kind::Object::new(BTreeMap::new());
-				let mut obj = crate::sir::Object::new();
-
-				for prop in props {
-					match prop {
-						PropOrSpread::Prop(prop) => match prop.as_ref() {
-							Prop::KeyValue(kv) => {
-								let key = kv.key.as_ident().unwrap().sym.clone();
-								let value = self.check_expr(&kv.value, expected_ty);
-
-								obj_ty.fields.insert(key.clone(), value.ty);
-								obj.fields.push((key, value.id));
-							}
-							_ => todo!("{:#?}", prop),
-						},
-						_ => todo!("{:#?}", prop),
-					}
-				}
-
-				self.add_local(self.tcx.new_object(obj_ty), Value::Obj(obj))
-			}
+			Expr::Object(ObjectLit { props, .. }) => self.check_object_lit(props, expected_ty),
 			Expr::Arrow(closure) => {
-				let mut params = vec![];
-
-				for param in &closure.params {
-					match param {
-						Pat::Ident(ident) => {
-							let name = Symbol::new(ident.to_id());
-							let param_type = if let Some(type_ann) = &ident.type_ann {
-								self.build_ts_type(&type_ann.type_ann)
-							} else {
-								// If no type annotation, try to get from bindings (for closure context)
-								if let Some(binding) = self.get_binding(&name) {
-									binding.ty
-								} else {
-									// If no binding found, use unknown type
-									self.constants.unknown
-								}
-							};
-
-							params.push((name, param_type));
-						}
-						_ => todo!("{:#?}", param),
-					};
-				}
+				let type_params = self.bind_type_params(closure.type_params.as_deref());
+				let params = self.build_params(&closure.params, &|pat| match pat {
+					// If no type annotation, try to get from bindings (for closure context)
+					Pat::Ident(ident) => match self.get_binding(&Symbol::new(ident.to_id())) {
+						Some(binding) => binding.ty,
+						// If no binding found, use unknown type
+						None => self.constants.unknown,
+					},
+					_ => self.param_missing_type_ann(pat),
+				});
 
 				match closure.body.as_ref() {
 					BlockStmtOrExpr::Expr(body) => {
 						// TODO: use FunctionChecker or BaseChecker
+						for (name, ty) in params.bindings() {
+							let param = self.add_local(*ty, Value::Param);
+							self.set_binding(name, Some(param), *ty, false);
+						}
+
 						let ret = self.check_expr(body, expected_ty);
 
 						if let Some(return_type) = &closure.return_type {
@@ -570,10 +723,9 @@ impl<'tcx> BaseChecker<'tcx> {
 						}
 
 						self.add_local(
-							self.tcx.new_function(crate::kind::Function {
-								params,
-								ret: ret.ty,
-							}),
+							self.tcx.new_function(
+								params.into_function(ret.ty).with_type_params(type_params),
+							),
 							Value::Closure(),
 						)
 					}
@@ -584,6 +736,7 @@ impl<'tcx> BaseChecker<'tcx> {
 						};
 
 						let checker = FunctionChecker::new(self.tcx, params, ret);
+						checker.set_class_scope(self.nested_class_scope());
 
 						for (name, var) in self.bindings.borrow().iter() {
 							let param = checker.add_local(var.ty, Value::Param);
@@ -596,22 +749,33 @@ impl<'tcx> BaseChecker<'tcx> {
 							self.add_error_with_span(error.kind, error.span);
 						}
 
-						self.add_local(self.tcx.new_function(result.ty), Value::Closure())
+						self.add_local(
+							self.tcx
+								.new_function(result.ty.with_type_params(type_params)),
+							Value::Closure(),
+						)
 					}
 				}
 			}
 			Expr::New(NewExpr {
-				callee, args, span, ..
+				callee,
+				args,
+				type_args,
+				span,
+				..
 			}) => {
 				let callee = self.check_expr(callee, expected_ty);
 
 				let class = match callee.ty.kind() {
-					TyKind::Class(class) => class,
-					_ => {
-						self.add_error_with_span(ErrorKind::NotConstructable, *span);
-						return self.add_local(self.constants.err, Value::Err);
-					}
+					TyKind::Class(class) => Some(class),
+					_ => None,
 				};
+				// an object type may be constructed by its construct signatures
+				let signature = self.object_signature(callee.ty, true);
+				if class.is_none() && signature.is_none() {
+					self.add_error_with_span(ErrorKind::NotConstructable, *span);
+					return self.add_local(self.constants.err, Value::Err);
+				}
 
 				let args = match args {
 					Some(args) => args,
@@ -621,44 +785,95 @@ impl<'tcx> BaseChecker<'tcx> {
 					}
 				};
 
-				let args = args.iter().map(|ExprOrSpread { expr, spread }| {
-					if spread.is_some() {
-						todo!()
-					}
+				let Some(class) = class else {
+					let args = self.check_args(args, expected_ty);
+					let type_args: Option<Vec<_>> = type_args.as_ref().map(|type_args| {
+						type_args
+							.params
+							.iter()
+							.map(|ty| self.build_ts_type(ty))
+							.collect()
+					});
 
-					self.check_expr(expr, expected_ty)
-				});
+					let instance =
+						self.call_signature(signature.unwrap(), type_args.as_deref(), &args, *span);
+					return self.add_local(instance, Value::New(callee.id, args.ids));
+				};
+
+				if class.is_abstract() {
+					self.add_error_with_span(ErrorKind::AbstractClassInstantiation, *span);
+				}
+
+				let args = self.check_args(args, expected_ty);
+
+				let type_args = match type_args {
+					Some(type_args) => {
+						let type_args: Vec<_> = type_args
+							.params
+							.iter()
+							.map(|ty| self.build_ts_type(ty))
+							.collect();
+
+						if type_args.len() < self.required_type_args(class.type_params())
+							|| type_args.len() > class.type_params().len()
+						{
+							self.add_error_with_span(
+								ErrorKind::WrongNumTypeArgs(
+									class.type_params().len(),
+									type_args.len(),
+								),
+								*span,
+							);
+							return self.add_local(self.constants.err, Value::Err);
+						}
+
+						self.complete_type_args(class.type_params(), &type_args, *span)
+					}
+					None => {
+						let params = class.ctor().map(|ctor| ctor.params.as_slice());
+						let arg_tys: Vec<_> = args.fixed.iter().map(|arg| arg.ty).collect();
+
+						// type parameters never appear at the top level of an instance type,
+						// so inferred literals are widened like in TypeScript
+						self.infer_type_args(class.type_params(), params.unwrap_or(&[]), &arg_tys)
+							.into_iter()
+							.map(|ty| self.widen(ty))
+							.collect()
+					}
+				};
+				let subst = self.type_param_subst(class.type_params(), &type_args);
 
 				let instance = self.add_local(
-					self.tcx.new_interface(class.interface().clone()),
-					Value::New(callee.id, args.clone().map(|arg| arg.id).collect()),
+					self.instantiate(self.tcx.new_interface(class.interface().clone()), &subst),
+					Value::New(callee.id, args.ids.clone()),
 				);
 
 				if let Some(ctor) = class.ctor() {
-					let params = &ctor.params;
-
-					if params.len() != args.len() {
-						self.add_error_with_span(
-							ErrorKind::WrongNumArgs(params.len(), args.len()),
-							*span,
-						);
+					if let Some(error) = self.check_args_arity(ctor, &args) {
+						self.add_error_with_span(error, *span);
 						return instance;
 					}
 
-					for ((_, param), arg) in params.iter().zip(args) {
-						if !self.satisfies(*param, arg.ty) {
-							self.raise_type_error(*param, arg.ty, *span);
-						}
+					let ctor = self.instantiate_signature(ctor, &subst);
+					for error in self.args_errors(&ctor, &args) {
+						self.add_error_with_span(error, *span);
 					}
-				} else if args.len() != 0 {
+				} else if !args.fixed.is_empty() || args.variadic.is_some() {
 					// TS(2554)
-					self.add_error_with_span(ErrorKind::WrongNumArgs(0, args.len()), *span);
+					self.add_error_with_span(
+						ErrorKind::WrongNumArgs(0..=0, args.fixed.len()),
+						*span,
+					);
 				}
 
 				instance
 			}
 			Expr::Call(CallExpr {
-				callee, args, span, ..
+				callee,
+				args,
+				type_args,
+				span,
+				..
 			}) => {
 				let callee = self.check_expr(
 					match callee {
@@ -668,32 +883,28 @@ impl<'tcx> BaseChecker<'tcx> {
 					expected_ty,
 				);
 
-				let function = match callee.ty.kind() {
-					TyKind::Function(function) => function,
-					_ => {
-						self.add_error_with_span(ErrorKind::NotCallable(callee.ty), *span);
-						return self.add_local(self.constants.err, Value::Err);
-					}
-				};
-
-				let args = args.iter().map(|ExprOrSpread { expr, spread }| {
-					if spread.is_some() {
-						todo!()
-					}
-
-					self.check_expr(expr, expected_ty)
+				let args = self.check_args(args, expected_ty);
+				let type_args: Option<Vec<_>> = type_args.as_ref().map(|type_args| {
+					type_args
+						.params
+						.iter()
+						.map(|ty| self.build_ts_type(ty))
+						.collect()
 				});
 
-				for ((_, param), arg) in function.params.iter().zip(args.clone()) {
-					if !self.satisfies(*param, arg.ty) {
-						self.raise_type_error(*param, arg.ty, *span);
-					}
-				}
+				let signature = match callee.ty.kind() {
+					TyKind::Function(_) | TyKind::Overloaded(_) => Some(callee.ty),
+					// an object type may be called by its call signatures
+					_ => self.object_signature(callee.ty, false),
+				};
+				let Some(signature) = signature else {
+					self.add_error_with_span(ErrorKind::NotCallable(callee.ty), *span);
+					return self.add_local(self.constants.err, Value::Err);
+				};
 
-				self.add_local(
-					function.ret,
-					Value::Call(callee.id, args.map(|arg| arg.id).collect()),
-				)
+				let ret = self.call_signature(signature, type_args.as_deref(), &args, *span);
+
+				self.add_local(ret, Value::Call(callee.id, args.ids))
 			}
 			Expr::Array(array) => {
 				// Check if we have an expected array type to get the expected element type
@@ -702,18 +913,51 @@ impl<'tcx> BaseChecker<'tcx> {
 					_ => None,
 				});
 
-				let elements: Vec<_> = array
-					.elems
-					.iter()
-					.filter_map(|elem| elem.as_ref())
-					.map(|ExprOrSpread { expr, spread }| {
-						if spread.is_some() {
-							todo!("spread in array literal")
-						}
-						// Pass the expected element type when checking each element
-						self.check_expr(expr, expected_ty)
-					})
-					.collect();
+				// A literal matching an expected tuple's arity is checked as a tuple
+				if let Some(TyKind::Tuple(tuple)) = expected_ty.map(|ty| ty.kind())
+					&& tuple.elements.len() == array.elems.len()
+					&& array
+						.elems
+						.iter()
+						.all(|elem| elem.as_ref().is_some_and(|elem| elem.spread.is_none()))
+				{
+					let elements: Vec<_> = array
+						.elems
+						.iter()
+						.flatten()
+						.zip(&tuple.elements)
+						.map(|(elem, expected)| self.check_expr(&elem.expr, Some(*expected)))
+						.collect();
+
+					return self.add_local(
+						self.tcx
+							.new_tuple(elements.iter().map(|elem| elem.ty).collect()),
+						Value::Array(elements.into_iter().map(|elem| elem.id).collect()),
+					);
+				}
+
+				let mut ids = vec![];
+				let mut elements = vec![];
+				for elem in array.elems.iter().flatten() {
+					// Pass the expected element type when checking each element
+					let (id, spread) = self.check_spread(elem, expected_ty);
+					ids.push(id);
+					elements.extend(spread);
+				}
+
+				// spread tuples are concatenated when a tuple of their length is expected
+				if let Some(TyKind::Tuple(tuple)) = expected_ty.map(|ty| ty.kind())
+					&& tuple.elements.len() == elements.len()
+					&& elements
+						.iter()
+						.all(|elem| matches!(elem, Element::Single(_)))
+				{
+					return self.add_local(
+						self.tcx
+							.new_tuple(elements.iter().map(|elem| elem.ty()).collect()),
+						Value::Array(ids),
+					);
+				}
 
 				if elements.is_empty() {
 					self.add_local(
@@ -721,7 +965,7 @@ impl<'tcx> BaseChecker<'tcx> {
 							Some(ty) => ty,
 							None => self.constants.unknown,
 						}),
-						Value::Array(vec![]),
+						Value::Array(ids),
 					)
 				} else {
 					// Infer the element type from actual elements
@@ -734,14 +978,14 @@ impl<'tcx> BaseChecker<'tcx> {
 								.iter()
 								.map(|e| {
 									if preserve {
-										e.ty
+										e.ty()
 									} else {
 										// Normalize literal types to their base types
-										match e.ty.kind() {
+										match e.ty().kind() {
 											TyKind::String(_) => self.constants.string,
 											TyKind::Number(_) => self.constants.number,
 											TyKind::Boolean(_) => self.constants.boolean,
-											_ => e.ty,
+											_ => e.ty(),
 										}
 									}
 								})
@@ -753,11 +997,11 @@ impl<'tcx> BaseChecker<'tcx> {
 								.iter()
 								.map(|e| {
 									// Normalize literal types to their base types for array inference
-									match e.ty.kind() {
+									match e.ty().kind() {
 										TyKind::String(_) => self.constants.string,
 										TyKind::Number(_) => self.constants.number,
 										TyKind::Boolean(_) => self.constants.boolean,
-										_ => e.ty,
+										_ => e.ty(),
 									}
 								})
 								.collect();
@@ -771,15 +1015,10 @@ impl<'tcx> BaseChecker<'tcx> {
 					};
 
 					// Always use the inferred type - the satisfies check happens in decl.rs
-					self.add_local(
-						self.tcx.new_array(inferred_element_type),
-						Value::Array(elements.into_iter().map(|e| e.id).collect()),
-					)
+					self.add_local(self.tcx.new_array(inferred_element_type), Value::Array(ids))
 				}
 			}
 			Expr::Tpl(tpl) => {
-				// Template literals always result in string type
-				// We could track the literal value for const strings, but for now just return string
 				let parts: Vec<_> = tpl
 					.exprs
 					.iter()
@@ -788,8 +1027,26 @@ impl<'tcx> BaseChecker<'tcx> {
 
 				// All interpolated expressions should be convertible to string
 				// In TypeScript, this is implicit
+				let ty = self.tcx.new_template(
+					tpl.quasis
+						.iter()
+						.map(|quasi| quasi.cooked.clone().unwrap_or_else(|| quasi.raw.clone()))
+						.collect(),
+					parts.iter().map(|part| part.ty).collect(),
+				);
+
+				// a template of literals is a literal; otherwise it's a 'string',
+				// unless a template literal type is expected
+				let ty = match ty.kind() {
+					TyKind::String(Some(_)) => ty,
+					_ if expected_ty.is_some_and(|ty| matches!(ty.kind(), TyKind::Template(_))) => {
+						ty
+					}
+					_ => self.constants.string,
+				};
+
 				self.add_local(
-					self.constants.string,
+					ty,
 					Value::Template(parts.into_iter().map(|p| p.id).collect()),
 				)
 			}
@@ -810,32 +1067,34 @@ impl<'tcx> BaseChecker<'tcx> {
 				// For arrays, this means keeping literal types of elements
 				match expr.as_ref() {
 					Expr::Array(array) => {
-						let elements: Vec<_> = array
-							.elems
-							.iter()
-							.filter_map(|elem| elem.as_ref())
-							.map(|ExprOrSpread { expr, spread }| {
-								if spread.is_some() {
-									todo!("spread in array literal")
-								}
-								self.check_expr(expr, expected_ty)
-							})
-							.collect();
+						let mut ids = vec![];
+						let mut elements = vec![];
+						for elem in array.elems.iter().flatten() {
+							let (id, spread) = self.check_spread(elem, expected_ty);
+							ids.push(id);
+							elements.extend(spread);
+						}
 
 						if elements.is_empty() {
 							// Empty array with const assertion
 							self.add_local(
 								self.tcx.new_array(self.constants.never),
-								Value::Array(vec![]),
+								Value::Array(ids),
 							)
-						} else {
+						} else if elements
+							.iter()
+							.all(|elem| matches!(elem, Element::Single(_)))
+						{
 							// With const assertion, create a tuple type instead of array type
 							// This preserves the exact structure: ["pending", "done"] as const -> ["pending", "done"]
-							let element_types: Vec<_> = elements.iter().map(|e| e.ty).collect();
+							let element_types: Vec<_> = elements.iter().map(|e| e.ty()).collect();
 
+							self.add_local(self.tcx.new_tuple(element_types), Value::Array(ids))
+						} else {
+							// a spread array has no fixed length
 							self.add_local(
-								self.tcx.new_tuple(element_types),
-								Value::Array(elements.into_iter().map(|e| e.id).collect()),
+								self.tcx.new_array(self.union_of_elements(&elements)),
+								Value::Array(ids),
 							)
 						}
 					}
@@ -848,14 +1107,33 @@ impl<'tcx> BaseChecker<'tcx> {
 		}
 	}
 
-	fn handle_property_access(
+	pub(super) fn handle_property_access(
 		&self,
 		obj: crate::sir::Local<'tcx>,
 		key: swc_atoms::Atom,
 		span: swc_common::Span,
 	) -> crate::sir::Local<'tcx> {
 		match obj.ty.kind() {
-			TyKind::Object(obj_ty) => match obj_ty.get_prop(&key) {
+			// members of a type parameter are those of its constraint
+			TyKind::TypeParameter(TypeParameter {
+				constraint: Some(constraint),
+				..
+			}) => self.handle_property_access(
+				Local {
+					id: obj.id,
+					ty: *constraint,
+				},
+				key,
+				span,
+			),
+			TyKind::Object(_)
+			| TyKind::Interface(_)
+			| TyKind::Class(_)
+			| TyKind::Array(_)
+			| TyKind::Tuple(_)
+			| TyKind::Enum(_)
+			| TyKind::EnumMember(_)
+			| TyKind::EnumObject(_) => match self.lookup_property(obj.ty, &key) {
 				Some(ty) => self.add_local(ty, Value::Member(obj.id, key)),
 				None => {
 					self.add_error_with_span(
@@ -865,25 +1143,32 @@ impl<'tcx> BaseChecker<'tcx> {
 					self.add_local(self.constants.err, Value::Member(obj.id, key))
 				}
 			},
-			TyKind::Interface(interface) => match interface.fields().get(&key) {
-				Some(ty) => self.add_local(*ty, Value::Member(obj.id, key)),
-				None => {
+			TyKind::Intersection(intersection) => {
+				let props: BTreeSet<_> = intersection
+					.parts()
+					.iter()
+					.filter_map(|part| self.lookup_property(*part, &key))
+					.collect();
+
+				if props.is_empty() {
 					self.add_error_with_span(
 						ErrorKind::PropertyDoesNotExist(obj.ty, key.clone()),
 						span,
 					);
-					self.add_local(self.constants.err, Value::Member(obj.id, key))
+					return self.add_local(self.constants.err, Value::Member(obj.id, key));
 				}
-			},
+
+				self.add_local(self.tcx.new_intersection(props), Value::Member(obj.id, key))
+			}
 			TyKind::Union(uni) => {
 				let mut prop_arms = BTreeSet::new();
 
 				for arm in uni.arms() {
-					if let TyKind::Object(obj) = arm.kind() {
-						if let Some(prop) = obj.get_prop(&key) {
-							prop_arms.insert(prop);
-							continue;
-						}
+					if let TyKind::Object(obj) = arm.kind()
+						&& let Some(prop) = obj.get_prop(&key)
+					{
+						prop_arms.insert(self.optional_prop_ty(prop, obj.is_optional(&key)));
+						continue;
 					}
 
 					self.add_error_with_span(
@@ -929,13 +1214,386 @@ impl<'tcx> BaseChecker<'tcx> {
 		}
 	}
 
-	fn handle_computed_access(
+	/// Checks a call of a function or an overloaded one, and returns the return type
+	fn call_signature(
+		&self,
+		signature: Ty<'tcx>,
+		type_args: Option<&[Ty<'tcx>]>,
+		args: &Args<'tcx>,
+		span: swc_common::Span,
+	) -> Ty<'tcx> {
+		match signature.kind() {
+			TyKind::Function(function) => {
+				let (ret, errors) = self.check_call(function, type_args, args, span);
+				for error in errors {
+					self.add_error_with_span(error, span);
+				}
+				ret
+			}
+			TyKind::Overloaded(overloaded) => {
+				self.resolve_overloads(overloaded, type_args, args, span)
+			}
+			_ => unreachable!(),
+		}
+	}
+
+	/// Checks the arguments of a call against a signature, instantiating a generic one with the
+	/// explicit or inferred type arguments; returns the return type and the errors of the call
+	pub(super) fn check_call(
+		&self,
+		function: &Function<'tcx>,
+		type_args: Option<&[Ty<'tcx>]>,
+		args: &Args<'tcx>,
+		span: swc_common::Span,
+	) -> (Ty<'tcx>, Vec<ErrorKind<'tcx>>) {
+		let instantiated;
+		let function = if let Some(type_args) = type_args {
+			if type_args.len() < self.required_type_args(&function.type_params)
+				|| type_args.len() > function.type_params.len()
+			{
+				return (
+					self.constants.err,
+					vec![ErrorKind::WrongNumTypeArgs(
+						function.type_params.len(),
+						type_args.len(),
+					)],
+				);
+			}
+
+			let type_args = self.complete_type_args(&function.type_params, type_args, span);
+
+			instantiated = self.instantiate_function(function, &type_args);
+			&instantiated
+		} else if function.is_generic() {
+			let arg_tys: Vec<_> = args
+				.fixed
+				.iter()
+				.map(|arg| arg.ty)
+				.chain(args.variadic.iter().flatten().copied())
+				.collect();
+			// the parameters each argument is passed to, including the elements of a rest parameter
+			let params: Vec<_> = (0..arg_tys.len())
+				.map_while(|index| function.param_at(index))
+				.map(|(name, ty)| (name.clone(), ty))
+				.collect();
+			let type_args = self.infer_type_args(&function.type_params, &params, &arg_tys);
+
+			instantiated = self.instantiate_function(function, &type_args);
+			&instantiated
+		} else {
+			function
+		};
+
+		(function.ret, self.args_errors(function, args))
+	}
+
+	/// The object of a member access, which may also be a const enum
+	fn check_member_object(&self, obj: &Expr, expected_ty: Option<Ty<'tcx>>) -> Local<'tcx> {
+		if let Expr::Ident(ident) = obj
+			&& let Some(binding) = self.get_binding(&Symbol::new(ident.to_id()))
+			&& let Some(current) = binding.current
+			&& let TyKind::EnumObject(_) = binding.ty.kind()
+		{
+			return Local {
+				id: current.id,
+				ty: binding.ty,
+			};
+		}
+
+		self.check_expr(obj, expected_ty)
+	}
+
+	/// Reading an optional property gives `T | undefined`
+	pub fn optional_prop_ty(&self, ty: Ty<'tcx>, is_optional: bool) -> Ty<'tcx> {
+		if is_optional {
+			self.tcx
+				.new_union(BTreeSet::from([ty, self.constants.undefined]))
+		} else {
+			ty
+		}
+	}
+
+	/// Looks up the type of a property without reporting errors
+	pub fn lookup_property(&self, ty: Ty<'tcx>, key: &swc_atoms::Atom) -> Option<Ty<'tcx>> {
+		self.lookup_member(ty, key, true)
+	}
+
+	/// Like `lookup_property`, but index signatures only cover undeclared keys if `with_index` is set
+	pub fn lookup_member(
+		&self,
+		ty: Ty<'tcx>,
+		key: &swc_atoms::Atom,
+		with_index: bool,
+	) -> Option<Ty<'tcx>> {
+		match ty.kind() {
+			TyKind::Object(obj) => obj
+				.get_prop(key)
+				.map(|ty| self.optional_prop_ty(ty, obj.is_optional(key)))
+				.or_else(|| obj.index().get_prop(key).filter(|_| with_index)),
+			TyKind::Interface(interface) => interface
+				.get_prop(key)
+				.map(|ty| self.optional_prop_ty(ty, interface.is_optional(key)))
+				.or_else(|| interface.index().get_prop(key).filter(|_| with_index)),
+			// the members of a class are its static ones
+			TyKind::Class(class) => {
+				self.lookup_member(self.tcx.new_interface(class.statics()), key, with_index)
+			}
+			TyKind::Number(_) => self.constants.proto_number.get(key).copied(),
+			TyKind::String(_) => self.constants.proto_string.get(key).copied(),
+			TyKind::Array(array) => self.array_member(array.element, array.readonly, key),
+			TyKind::Tuple(tuple) => match key.as_ref() {
+				"length" => Some(self.tcx.new_const_number(tuple.elements.len() as f64)),
+				_ => tuple
+					.elements
+					.get(key.parse::<usize>().unwrap_or(usize::MAX))
+					.copied()
+					.or_else(|| {
+						let element = match tuple.elements.len() {
+							0 => self.constants.never,
+							_ => self.tcx.new_union(tuple.elements.iter().copied().collect()),
+						};
+						self.array_member(element, tuple.readonly, key)
+					}),
+			},
+			TyKind::EnumObject(enum_) => enum_.get_member(key).map(|_| {
+				self.tcx
+					.new_enum_member(EnumMember::new(enum_.clone(), key.clone()))
+			}),
+			// members of enum values are those of their values, e.g. 'toFixed'
+			TyKind::EnumMember(member) => self.lookup_member(member.value(), key, with_index),
+			TyKind::Enum(enum_) => {
+				let props = enum_
+					.members()
+					.iter()
+					.map(|(_, value)| self.lookup_member(*value, key, with_index))
+					.collect::<Option<BTreeSet<_>>>()?;
+
+				Some(self.tcx.new_union(props))
+			}
+			TyKind::TypeParameter(TypeParameter {
+				constraint: Some(constraint),
+				..
+			}) => self.lookup_member(*constraint, key, with_index),
+			TyKind::Intersection(intersection) => {
+				let props: BTreeSet<_> = intersection
+					.parts()
+					.iter()
+					.filter_map(|part| self.lookup_member(*part, key, with_index))
+					.collect();
+
+				(!props.is_empty()).then(|| self.tcx.new_intersection(props))
+			}
+			_ => None,
+		}
+	}
+
+	/// Members of `T[]`; the mutating methods are missing on `readonly T[]`
+	fn array_member(
+		&self,
+		element: Ty<'tcx>,
+		readonly: bool,
+		key: &swc_atoms::Atom,
+	) -> Option<Ty<'tcx>> {
+		let param = |(name, ty): &(&str, Ty<'tcx>)| {
+			(
+				Symbol::new((swc_atoms::Atom::new(*name), SyntaxContext::empty())),
+				*ty,
+			)
+		};
+		let method = |params: &[(&str, Ty<'tcx>)], optional: &[(&str, Ty<'tcx>)], ret: Ty<'tcx>| {
+			self.tcx.new_function(
+				Function::new(params.iter().chain(optional).map(param).collect(), ret)
+					.with_required(params.len()),
+			)
+		};
+		// takes any number of arguments of the given type
+		let variadic = |rest: (&str, Ty<'tcx>), ret: Ty<'tcx>| {
+			self.tcx.new_function(
+				Function::new(vec![], ret)
+					.with_rest(Some(param(&(rest.0, self.tcx.new_array(rest.1))))),
+			)
+		};
+		let array = self.tcx.new_array(element);
+		let maybe_element = self.optional_prop_ty(element, true);
+		let number = self.constants.number;
+
+		Some(match key.as_ref() {
+			"length" => number,
+			"at" => method(&[("index", number)], &[], maybe_element),
+			"concat" => variadic(("items", array), array),
+			"includes" => method(
+				&[("searchElement", element)],
+				&[("fromIndex", number)],
+				self.constants.boolean,
+			),
+			"indexOf" | "lastIndexOf" => method(
+				&[("searchElement", element)],
+				&[("fromIndex", number)],
+				number,
+			),
+			"join" => method(
+				&[],
+				&[("separator", self.constants.string)],
+				self.constants.string,
+			),
+			"slice" => method(&[], &[("start", number), ("end", number)], array),
+			"push" | "unshift" if !readonly => variadic(("items", element), number),
+			"pop" | "shift" if !readonly => method(&[], &[], maybe_element),
+			"reverse" if !readonly => method(&[], &[], array),
+			"sort" if !readonly => {
+				let compare_fn = self.tcx.new_function(Function::new(
+					vec![param(&("a", element)), param(&("b", element))],
+					number,
+				));
+
+				method(&[], &[("compareFn", compare_fn)], array)
+			}
+			"fill" if !readonly => method(
+				&[("value", element)],
+				&[("start", number), ("end", number)],
+				array,
+			),
+			"splice" if !readonly => self.tcx.new_function(
+				Function::new(
+					vec![param(&("start", number)), param(&("deleteCount", number))],
+					array,
+				)
+				.with_required(1)
+				.with_rest(Some(param(&("items", array)))),
+			),
+			_ => return None,
+		})
+	}
+
+	/// The key of `obj.key` or `obj.#key`
+	fn member_prop_key(prop: &MemberProp) -> (swc_atoms::Atom, Span) {
+		match prop {
+			MemberProp::Ident(ident) => (ident.sym.clone(), ident.span),
+			MemberProp::PrivateName(name) => (Self::private_key(name), name.span),
+			MemberProp::Computed(_) => {
+				unreachable!("the key of a computed member is an expression")
+			}
+		}
+	}
+
+	/// Reports an access to a private or protected member outside the classes which may access it
+	fn check_member_visibility(&self, ty: Ty<'tcx>, key: &swc_atoms::Atom, span: Span) {
+		let visibility = match ty.kind() {
+			TyKind::Interface(interface) => interface.visibility().get(key).cloned(),
+			TyKind::Class(class) => class.statics().visibility().get(key).cloned(),
+			_ => return,
+		};
+		let Some(visibility) = visibility else {
+			return;
+		};
+		let classes = self
+			.class_scope()
+			.map(|scope| scope.classes)
+			.unwrap_or_default();
+
+		let error = match &visibility {
+			Visibility::Private(class) if classes.first() != Some(class) => {
+				match key.starts_with('#') {
+					true => ErrorKind::PrivateIdentifierNotAccessible(key.clone(), class.clone()),
+					false => ErrorKind::PrivateMemberNotAccessible(key.clone(), class.clone()),
+				}
+			}
+			Visibility::Protected(class) if !classes.contains(class) => {
+				ErrorKind::ProtectedMemberNotAccessible(key.clone(), class.clone())
+			}
+			_ => return,
+		};
+		self.add_error_with_span(error, span);
+	}
+
+	/// The type whose members are those of `ty`, which is the class of `this` for `this`
+	fn apparent_receiver(&self, ty: Ty<'tcx>) -> Ty<'tcx> {
+		match ty.kind() {
+			TyKind::TypeParameter(TypeParameter {
+				name,
+				constraint: Some(constraint),
+				..
+			}) if *name == Symbol::new_this() => *constraint,
+			_ => ty,
+		}
+	}
+
+	/// Whether `ty` is the instance of the class whose constructor is being checked, which may
+	/// assign to its readonly fields
+	fn is_constructing(&self, ty: Ty<'tcx>) -> bool {
+		let TyKind::Interface(interface) = ty.kind() else {
+			return false;
+		};
+
+		self.class_scope().is_some_and(|scope| {
+			scope.is_constructor && scope.classes.first() == Some(interface.name())
+		})
+	}
+
+	/// Whether `key` is a readonly property of an object, interface or enum
+	fn is_readonly_property(&self, ty: Ty<'tcx>, key: &swc_atoms::Atom) -> bool {
+		match ty.kind() {
+			TyKind::Object(obj) => obj.is_readonly(key),
+			TyKind::Interface(interface) => interface.is_readonly(key),
+			TyKind::Class(class) => class.statics().is_readonly(key),
+			TyKind::EnumObject(_) => true,
+			_ => false,
+		}
+	}
+
+	/// Type of `obj[index]` on an object or interface: a literal key is looked up as a property,
+	/// any other string or number key through the index signatures (`None` if there is none)
+	fn lookup_computed(
+		&self,
+		obj: Ty<'tcx>,
+		index: Ty<'tcx>,
+	) -> Result<Option<Ty<'tcx>>, ErrorKind<'tcx>> {
+		let key = match index.kind() {
+			TyKind::String(Some(key)) => key.clone(),
+			TyKind::Number(Some(value)) => swc_atoms::Atom::new(value.to_string()),
+			TyKind::String(None) | TyKind::Number(None) => {
+				let index_signatures = match obj.kind() {
+					TyKind::Object(obj) => obj.index(),
+					TyKind::Interface(interface) => interface.index(),
+					_ => unreachable!(),
+				};
+
+				return Ok(match index.kind() {
+					TyKind::Number(_) => index_signatures.get_number(),
+					_ => index_signatures.string,
+				});
+			}
+			_ => return Err(ErrorKind::TypeCannotBeUsedAsIndexType(index)),
+		};
+
+		match self.lookup_property(obj, &key) {
+			Some(ty) => Ok(Some(ty)),
+			None => Err(ErrorKind::PropertyDoesNotExist(obj, key)),
+		}
+	}
+
+	pub(super) fn handle_computed_access(
 		&self,
 		obj: crate::sir::Local<'tcx>,
 		index: crate::sir::Local<'tcx>,
 		span: swc_common::Span,
 	) -> crate::sir::Local<'tcx> {
 		match obj.ty.kind() {
+			// tuples and type parameters are indexed like in types (e.g. 'T[K]')
+			_ if matches!(obj.ty.kind(), TyKind::Tuple(_))
+				|| self.is_deferred(obj.ty)
+				|| self.is_deferred(index.ty) =>
+			{
+				match self.indexed_access(obj.ty, index.ty) {
+					Ok(ty) => {
+						self.add_local(ty, Value::Member(obj.id, swc_atoms::Atom::new("element")))
+					}
+					Err(error) => {
+						self.add_error_with_span(error, span);
+						self.add_local(self.constants.err, Value::Err)
+					}
+				}
+			}
 			TyKind::Array(array) => {
 				// For arrays, index should be number and we return element type
 				match index.ty.kind() {
@@ -955,29 +1613,42 @@ impl<'tcx> BaseChecker<'tcx> {
 					}
 				}
 			}
-			TyKind::Object(_) => {
-				// For objects, index should be string
-				match index.ty.kind() {
-					TyKind::String(Some(key)) => {
-						// We know the exact key
-						self.handle_property_access(obj, key.clone(), span)
-					}
-					TyKind::String(None) => {
-						// Dynamic string key - we can't statically determine the type
-						// In a more sophisticated implementation, we'd use index signatures
-						self.add_local(
-							self.constants.unknown,
-							Value::Member(obj.id, swc_atoms::Atom::new("computed")),
-						)
-					}
-					_ => {
-						self.add_error_with_span(
-							ErrorKind::PropertyDoesNotExist(
-								obj.ty,
-								swc_atoms::Atom::new("computed"),
-							),
-							span,
-						);
+			TyKind::EnumObject(enum_) => match index.ty.kind() {
+				TyKind::String(Some(key)) if enum_.get_member(key).is_some() => self.add_local(
+					self.lookup_property(obj.ty, key).unwrap(),
+					Value::Member(obj.id, key.clone()),
+				),
+				// the reverse mapping of a numeric enum gives the name of a member
+				TyKind::Number(_)
+					if !enum_.is_const()
+						&& enum_
+							.members()
+							.iter()
+							.any(|(_, value)| matches!(value.kind(), TyKind::Number(_))) =>
+				{
+					self.add_local(
+						self.constants.string,
+						Value::Member(obj.id, swc_atoms::Atom::new("computed")),
+					)
+				}
+				_ => {
+					self.add_error_with_span(
+						ErrorKind::TypeCannotBeUsedAsIndexType(index.ty),
+						span,
+					);
+					self.add_local(self.constants.err, Value::Err)
+				}
+			},
+			TyKind::Object(_) | TyKind::Interface(_) => {
+				// For objects, index should be string or number
+				match self.lookup_computed(obj.ty, index.ty) {
+					Ok(ty) => self.add_local(
+						// Dynamic key without an index signature - we can't statically determine the type
+						ty.unwrap_or(self.constants.unknown),
+						Value::Member(obj.id, swc_atoms::Atom::new("computed")),
+					),
+					Err(error) => {
+						self.add_error_with_span(error, span);
 						self.add_local(self.constants.err, Value::Err)
 					}
 				}
@@ -0,0 +1,745 @@
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap},
+	rc::Rc,
+};
+
+use swc_atoms::Atom;
+use swc_common::Span;
+use swc_ecma_ast::TsTypeParamDecl;
+
+use crate::{
+	Ty, TyKind,
+	checker::errors::ErrorKind,
+	kind::{
+		Array, Conditional, Function, IndexSignatures, Interface, Mapped, MappedModifier, Object,
+		Overloaded, Signatures, Tuple, TypeParameter,
+	},
+	symbol::Symbol,
+};
+
+use super::BaseChecker;
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Creates a type parameter for each declared parameter and binds it by name,
+	/// so that annotations in the signature (and the body) can refer to it.
+	/// Constraints and defaults may refer to the preceding parameters.
+	pub fn bind_type_params(&self, decl: Option<&TsTypeParamDecl>) -> Vec<Ty<'tcx>> {
+		let Some(decl) = decl else {
+			return vec![];
+		};
+
+		decl.params
+			.iter()
+			.map(|param| {
+				let name = Symbol::new(param.name.to_id());
+				let constraint = param
+					.constraint
+					.as_ref()
+					.map(|constraint| self.build_ts_type(constraint));
+				let default = param
+					.default
+					.as_ref()
+					.map(|default| self.build_ts_type(default));
+
+				if let (Some(constraint), Some(default)) = (constraint, default)
+					&& !self.satisfies(constraint, default)
+				{
+					self.add_error_with_span(
+						ErrorKind::TypeArgNotSatisfyConstraint(default, constraint),
+						param.span,
+					);
+				}
+
+				let ty = self.tcx.new_type_parameter(TypeParameter::new(
+					name.clone(),
+					constraint,
+					default,
+				));
+
+				// NOTE: type parameters are hygienic (resolved by swc), so binding them in the current scope does not shadow anything
+				self.set_binding(&name, None, ty, false);
+
+				ty
+			})
+			.collect()
+	}
+
+	pub fn set_type_param_bindings(&self, type_params: &[Ty<'tcx>]) {
+		for ty in type_params {
+			if let TyKind::TypeParameter(param) = ty.kind() {
+				self.set_binding(&param.name, None, *ty, false);
+			}
+		}
+	}
+
+	/// Replaces every type parameter in `ty` that appears in `subst`
+	pub fn instantiate(&self, ty: Ty<'tcx>, subst: &HashMap<Symbol, Ty<'tcx>>) -> Ty<'tcx> {
+		if subst.is_empty() {
+			return ty;
+		}
+
+		match ty.kind() {
+			TyKind::TypeParameter(param) => subst.get(&param.name).copied().unwrap_or(ty),
+			TyKind::Function(function) => self
+				.tcx
+				.new_function(self.instantiate_signature(function, subst)),
+			TyKind::Overloaded(overloaded) => self.tcx.new_overloaded(Overloaded::new(
+				overloaded
+					.signatures()
+					.iter()
+					.map(|ty| self.instantiate(*ty, subst))
+					.collect(),
+			)),
+			TyKind::Object(obj) => self.tcx.new_object(
+				Object::new(
+					obj.fields()
+						.iter()
+						.map(|(key, ty)| (key.clone(), self.instantiate(*ty, subst)))
+						.collect(),
+				)
+				.with_optional(obj.optional().clone())
+				.with_readonly(obj.readonly().clone())
+				.with_index(self.instantiate_index(obj.index(), subst))
+				.with_signatures(self.instantiate_signatures(obj.signatures(), subst)),
+			),
+			TyKind::Interface(interface) if !interface.type_args().is_empty() => {
+				self.tcx.new_interface(Rc::new(
+					Interface::new_with_type_args(
+						interface.name().clone(),
+						interface
+							.type_args()
+							.iter()
+							.map(|ty| self.instantiate(*ty, subst))
+							.collect(),
+						interface
+							.fields()
+							.iter()
+							.map(|(key, ty)| (key.clone(), self.instantiate(*ty, subst)))
+							.collect(),
+					)
+					.with_optional(interface.optional().clone())
+					.with_readonly(interface.readonly().clone())
+					.with_index(self.instantiate_index(interface.index(), subst))
+					.with_signatures(self.instantiate_signatures(interface.signatures(), subst))
+					.with_ancestors(
+						interface
+							.ancestors()
+							.iter()
+							.map(|ty| self.instantiate(*ty, subst))
+							.collect(),
+					)
+					.with_visibility(interface.visibility().clone()),
+				))
+			}
+			TyKind::Array(Array { element, readonly }) => {
+				let element = self.instantiate(*element, subst);
+
+				match readonly {
+					true => self.tcx.new_readonly_array(element),
+					false => self.tcx.new_array(element),
+				}
+			}
+			TyKind::Tuple(Tuple { elements, readonly }) => {
+				let elements = elements
+					.iter()
+					.map(|ty| self.instantiate(*ty, subst))
+					.collect();
+
+				match readonly {
+					true => self.tcx.new_readonly_tuple(elements),
+					false => self.tcx.new_tuple(elements),
+				}
+			}
+			TyKind::Union(uni) => self.tcx.new_union(
+				uni.arms()
+					.iter()
+					.map(|ty| self.instantiate(*ty, subst))
+					.collect(),
+			),
+			TyKind::Intersection(intersection) => self.tcx.new_intersection(
+				intersection
+					.parts()
+					.iter()
+					.map(|ty| self.instantiate(*ty, subst))
+					.collect(),
+			),
+			// distributes over a union given for a naked type parameter, e.g. 'T extends U ? X : Y'
+			TyKind::Conditional(conditional) => match conditional.check.kind() {
+				TyKind::TypeParameter(param) if subst.contains_key(&param.name) => {
+					let arms = match subst[&param.name].kind() {
+						TyKind::Union(uni) => uni.arms().clone(),
+						TyKind::Never => BTreeSet::new(),
+						_ => BTreeSet::from([subst[&param.name]]),
+					};
+
+					self.tcx.new_union(
+						arms.into_iter()
+							.map(|arm| {
+								let mut subst = subst.clone();
+								subst.insert(param.name.clone(), arm);
+								self.conditional(self.instantiate_conditional(conditional, &subst))
+							})
+							.filter(|ty| !matches!(ty.kind(), TyKind::Never))
+							.collect(),
+					)
+				}
+				_ => self.conditional(self.instantiate_conditional(conditional, subst)),
+			},
+			TyKind::Mapped(mapped) => self.mapped(Mapped {
+				param: mapped.param,
+				constraint: self.instantiate(mapped.constraint, subst),
+				source: mapped.source.map(|source| self.instantiate(source, subst)),
+				name: mapped.name.map(|name| self.instantiate(name, subst)),
+				ty: self.instantiate(mapped.ty, subst),
+				optional: mapped.optional,
+				readonly: mapped.readonly,
+			}),
+			TyKind::Template(template) => self.tcx.new_template(
+				template.quasis.clone(),
+				template
+					.types
+					.iter()
+					.map(|ty| self.instantiate(*ty, subst))
+					.collect(),
+			),
+			TyKind::StringMapping(mapping, ty) => {
+				self.string_mapping(*mapping, self.instantiate(*ty, subst))
+			}
+			TyKind::KeyOf(ty) => self.key_of(self.instantiate(*ty, subst)),
+			// NOTE: an invalid access is reported where the type was written
+			TyKind::IndexedAccess(access) => self
+				.indexed_access(
+					self.instantiate(access.object, subst),
+					self.instantiate(access.index, subst),
+				)
+				.unwrap_or(self.constants.err),
+			_ => ty,
+		}
+	}
+
+	fn instantiate_conditional(
+		&self,
+		conditional: &Conditional<'tcx>,
+		subst: &HashMap<Symbol, Ty<'tcx>>,
+	) -> Conditional<'tcx> {
+		Conditional {
+			check: self.instantiate(conditional.check, subst),
+			extends: self.instantiate(conditional.extends, subst),
+			true_ty: self.instantiate(conditional.true_ty, subst),
+			false_ty: self.instantiate(conditional.false_ty, subst),
+			infer: conditional.infer.clone(),
+		}
+	}
+
+	/// Resolves `check extends extends ? true_ty : false_ty` once `check` and `extends` are known,
+	/// binding the `infer` type parameters to what they match in `check`
+	pub fn conditional(&self, conditional: Conditional<'tcx>) -> Ty<'tcx> {
+		// the `infer` placeholders are the only type parameters `extends` may keep
+		let erased = conditional
+			.infer
+			.iter()
+			.filter_map(|ty| match ty.kind() {
+				TyKind::TypeParameter(param) => Some((param.name.clone(), self.constants.unknown)),
+				_ => None,
+			})
+			.collect();
+		if self.has_type_params(conditional.check)
+			|| self.has_type_params(self.instantiate(conditional.extends, &erased))
+		{
+			return self.tcx.new_conditional(conditional);
+		}
+
+		let mut candidates = conditional
+			.infer
+			.iter()
+			.filter_map(|ty| match ty.kind() {
+				TyKind::TypeParameter(param) => Some((param.name.clone(), vec![])),
+				_ => None,
+			})
+			.collect();
+		self.collect_candidates(conditional.extends, conditional.check, &mut candidates);
+
+		// placeholders without a match fall back to their constraint, like uninferred type arguments
+		let subst = conditional
+			.infer
+			.iter()
+			.filter_map(|ty| match ty.kind() {
+				TyKind::TypeParameter(param) => Some((
+					param.name.clone(),
+					self.resolve_candidates(&candidates[&param.name])
+						.or(param.constraint)
+						.unwrap_or(self.constants.unknown),
+				)),
+				_ => None,
+			})
+			.collect();
+
+		if self.satisfies(
+			self.instantiate(conditional.extends, &subst),
+			conditional.check,
+		) {
+			self.instantiate(conditional.true_ty, &subst)
+		} else {
+			conditional.false_ty
+		}
+	}
+
+	/// Resolves `{ [K in C as N]: X }` to an object once the keys `C` are known
+	pub fn mapped(&self, mapped: Mapped<'tcx>) -> Ty<'tcx> {
+		if self.has_type_params(mapped.constraint) {
+			return self.tcx.new_mapped(mapped);
+		}
+
+		let TyKind::TypeParameter(param) = mapped.param.kind() else {
+			unreachable!("mapped type keys must be TyKind::TypeParameter");
+		};
+		let keys = match mapped.constraint.kind() {
+			TyKind::Union(uni) => uni.arms().clone(),
+			_ => BTreeSet::from([mapped.constraint]),
+		};
+		let (source_optional, source_readonly) = match mapped.source.map(|source| source.kind()) {
+			Some(TyKind::Object(obj)) => (obj.optional().clone(), obj.readonly().clone()),
+			Some(TyKind::Interface(interface)) => {
+				(interface.optional().clone(), interface.readonly().clone())
+			}
+			_ => Default::default(),
+		};
+
+		let mut object = Object::new(BTreeMap::new());
+		for key in keys {
+			let subst = HashMap::from([(param.name.clone(), key)]);
+			let mut ty = self.instantiate(mapped.ty, &subst);
+
+			// the modifiers come from the source's member, whatever name it's remapped to
+			let source_key = match key.kind() {
+				TyKind::String(Some(key)) => Some(key.clone()),
+				TyKind::Number(Some(value)) => Some(Atom::new(value.to_string())),
+				_ => None,
+			};
+			let source_is_optional = source_key
+				.as_ref()
+				.is_some_and(|key| source_optional.contains(key));
+			let source_is_readonly = source_key
+				.as_ref()
+				.is_some_and(|key| source_readonly.contains(key));
+
+			// the source's optional members are read as 'T | undefined', but keep their declared type here
+			if source_is_optional && let TyKind::Union(uni) = ty.kind() {
+				ty = self.tcx.new_union(
+					uni.arms()
+						.iter()
+						.copied()
+						.filter(|arm| !matches!(arm.kind(), TyKind::Undefined))
+						.collect(),
+				);
+			}
+
+			let names = match mapped.name.map(|name| self.instantiate(name, &subst)) {
+				Some(name) => match name.kind() {
+					TyKind::Union(uni) => uni.arms().clone(),
+					_ => BTreeSet::from([name]),
+				},
+				None => BTreeSet::from([key]),
+			};
+
+			for name in names {
+				let name = match name.kind() {
+					TyKind::String(Some(name)) => name.clone(),
+					TyKind::Number(Some(value)) => Atom::new(value.to_string()),
+					TyKind::String(None) => {
+						object.index.string = Some(ty);
+						continue;
+					}
+					TyKind::Number(None) => {
+						object.index.number = Some(ty);
+						continue;
+					}
+					// e.g. 'never' filters the key out
+					_ => continue,
+				};
+
+				let is_optional = match mapped.optional {
+					Some(MappedModifier::Add) => true,
+					Some(MappedModifier::Remove) => false,
+					None => source_is_optional,
+				};
+				if is_optional {
+					object.optional.insert(name.clone());
+				}
+
+				let is_readonly = match mapped.readonly {
+					Some(MappedModifier::Add) => true,
+					Some(MappedModifier::Remove) => false,
+					None => source_is_readonly,
+				};
+				if is_readonly {
+					object.readonly.insert(name.clone());
+				}
+
+				object.fields.insert(name, ty);
+			}
+		}
+
+		self.tcx.new_object(object)
+	}
+
+	fn instantiate_index(
+		&self,
+		index: &IndexSignatures<'tcx>,
+		subst: &HashMap<Symbol, Ty<'tcx>>,
+	) -> IndexSignatures<'tcx> {
+		IndexSignatures {
+			string: index.string.map(|ty| self.instantiate(ty, subst)),
+			number: index.number.map(|ty| self.instantiate(ty, subst)),
+		}
+	}
+
+	fn instantiate_signatures(
+		&self,
+		signatures: &Signatures<'tcx>,
+		subst: &HashMap<Symbol, Ty<'tcx>>,
+	) -> Signatures<'tcx> {
+		let instantiate =
+			|tys: &[Ty<'tcx>]| tys.iter().map(|ty| self.instantiate(*ty, subst)).collect();
+
+		Signatures {
+			call: instantiate(&signatures.call),
+			construct: instantiate(&signatures.construct),
+		}
+	}
+
+	/// Instantiates a generic function with the given type arguments, dropping its type parameters
+	pub fn instantiate_function(
+		&self,
+		function: &Function<'tcx>,
+		type_args: &[Ty<'tcx>],
+	) -> Function<'tcx> {
+		let subst = self.type_param_subst(&function.type_params, type_args);
+
+		Function {
+			type_params: vec![],
+			..self.instantiate_signature(function, &subst)
+		}
+	}
+
+	/// Replaces the type parameters in the parameters and the return type of a signature
+	pub fn instantiate_signature(
+		&self,
+		function: &Function<'tcx>,
+		subst: &HashMap<Symbol, Ty<'tcx>>,
+	) -> Function<'tcx> {
+		let instantiate =
+			|(name, ty): &(Symbol, Ty<'tcx>)| (name.clone(), self.instantiate(*ty, subst));
+
+		Function {
+			type_params: function.type_params.clone(),
+			params: function.params.iter().map(instantiate).collect(),
+			required: function.required,
+			rest: function.rest.as_ref().map(instantiate),
+			ret: self.instantiate(function.ret, subst),
+		}
+	}
+
+	pub fn type_param_subst(
+		&self,
+		type_params: &[Ty<'tcx>],
+		type_args: &[Ty<'tcx>],
+	) -> HashMap<Symbol, Ty<'tcx>> {
+		type_params
+			.iter()
+			.zip(type_args)
+			.filter_map(|(param, arg)| match param.kind() {
+				TyKind::TypeParameter(param) => Some((param.name.clone(), *arg)),
+				_ => None,
+			})
+			.collect()
+	}
+
+	/// Returns the number of type arguments that must be given explicitly (those without defaults)
+	pub fn required_type_args(&self, type_params: &[Ty<'tcx>]) -> usize {
+		type_params
+			.iter()
+			.filter(
+				|ty| !matches!(ty.kind(), TyKind::TypeParameter(param) if param.default.is_some()),
+			)
+			.count()
+	}
+
+	/// Fills in defaults for the omitted trailing type arguments and checks every argument against its constraint
+	pub fn complete_type_args(
+		&self,
+		type_params: &[Ty<'tcx>],
+		type_args: &[Ty<'tcx>],
+		span: Span,
+	) -> Vec<Ty<'tcx>> {
+		let mut subst = HashMap::new();
+		let mut completed = vec![];
+
+		for (i, ty) in type_params.iter().enumerate() {
+			let TyKind::TypeParameter(param) = ty.kind() else {
+				unreachable!("type parameters must be TyKind::TypeParameter");
+			};
+
+			let arg = match type_args.get(i) {
+				Some(arg) => *arg,
+				None => param
+					.default
+					.map(|default| self.instantiate(default, &subst))
+					.unwrap_or(self.constants.unknown),
+			};
+
+			if let Some(constraint) = param.constraint {
+				let constraint = self.instantiate(constraint, &subst);
+
+				if !self.satisfies(constraint, arg) {
+					self.add_error_with_span(
+						ErrorKind::TypeArgNotSatisfyConstraint(arg, constraint),
+						span,
+					);
+				}
+			}
+
+			subst.insert(param.name.clone(), arg);
+			completed.push(arg);
+		}
+
+		completed
+	}
+
+	/// Instantiates a generic declaration referenced as a type, e.g. 'Box<number>'
+	pub fn apply_type_args(
+		&self,
+		generic: Ty<'tcx>,
+		type_params: &[Ty<'tcx>],
+		ty: Ty<'tcx>,
+		type_args: &[Ty<'tcx>],
+		span: Span,
+	) -> Ty<'tcx> {
+		if type_params.is_empty() && !type_args.is_empty() {
+			self.add_error_with_span(ErrorKind::NotGeneric(generic), span);
+			return self.constants.err;
+		}
+
+		let required = self.required_type_args(type_params);
+
+		if type_args.len() < required || type_args.len() > type_params.len() {
+			self.add_error_with_span(
+				ErrorKind::GenericRequiresTypeArgs(generic, required, type_params.len()),
+				span,
+			);
+			return self.constants.err;
+		}
+
+		let type_args = self.complete_type_args(type_params, type_args, span);
+
+		self.instantiate(ty, &self.type_param_subst(type_params, &type_args))
+	}
+
+	/// Infers the type arguments for `type_params` from the types of the arguments given to `params`.
+	/// Type parameters that cannot be inferred fall back to their default, their constraint or `unknown`.
+	pub fn infer_type_args(
+		&self,
+		type_params: &[Ty<'tcx>],
+		params: &[(Symbol, Ty<'tcx>)],
+		args: &[Ty<'tcx>],
+	) -> Vec<Ty<'tcx>> {
+		let mut candidates: BTreeMap<Symbol, Vec<Ty<'tcx>>> = type_params
+			.iter()
+			.filter_map(|ty| match ty.kind() {
+				TyKind::TypeParameter(param) => Some((param.name.clone(), vec![])),
+				_ => None,
+			})
+			.collect();
+
+		for ((_, param), arg) in params.iter().zip(args) {
+			self.collect_candidates(*param, *arg, &mut candidates);
+		}
+
+		let mut subst = HashMap::new();
+
+		type_params
+			.iter()
+			.map(|ty| {
+				let TyKind::TypeParameter(param) = ty.kind() else {
+					unreachable!("type parameters must be TyKind::TypeParameter");
+				};
+
+				let constraint = param
+					.constraint
+					.map(|constraint| self.instantiate(constraint, &subst));
+
+				let inferred = match self.resolve_candidates(&candidates[&param.name]) {
+					// NOTE: an argument violating the constraint is reported on argument checking
+					Some(inferred) => match constraint {
+						Some(constraint) if !self.satisfies(constraint, inferred) => constraint,
+						_ => inferred,
+					},
+					None => param
+						.default
+						.map(|default| self.instantiate(default, &subst))
+						.or(constraint)
+						.unwrap_or(self.constants.unknown),
+				};
+
+				subst.insert(param.name.clone(), inferred);
+				inferred
+			})
+			.collect()
+	}
+
+	pub(super) fn collect_candidates(
+		&self,
+		param: Ty<'tcx>,
+		arg: Ty<'tcx>,
+		candidates: &mut BTreeMap<Symbol, Vec<Ty<'tcx>>>,
+	) {
+		match (param.kind(), arg.kind()) {
+			(_, TyKind::Err) => {}
+			(TyKind::TypeParameter(param), _) => {
+				if let Some(candidates) = candidates.get_mut(&param.name) {
+					candidates.push(arg);
+				}
+			}
+			(TyKind::Array(param), TyKind::Array(arg)) => {
+				self.collect_candidates(param.element, arg.element, candidates)
+			}
+			(TyKind::Array(param), TyKind::Tuple(arg)) => {
+				for element in &arg.elements {
+					self.collect_candidates(param.element, *element, candidates);
+				}
+			}
+			(TyKind::Tuple(param), TyKind::Tuple(arg)) => {
+				for (param, arg) in param.elements.iter().zip(&arg.elements) {
+					self.collect_candidates(*param, *arg, candidates);
+				}
+			}
+			(TyKind::Function(param), TyKind::Function(arg)) => {
+				for ((_, param), (_, arg)) in param.params.iter().zip(&arg.params) {
+					self.collect_candidates(*param, *arg, candidates);
+				}
+				if let (Some((_, param)), Some((_, arg))) = (&param.rest, &arg.rest) {
+					self.collect_candidates(*param, *arg, candidates);
+				}
+				self.collect_candidates(param.ret, arg.ret, candidates);
+			}
+			(TyKind::Object(param), TyKind::Object(arg)) => {
+				for (key, param) in param.fields() {
+					if let Some(arg) = arg.get_prop(key) {
+						self.collect_candidates(*param, arg, candidates);
+					}
+				}
+			}
+			(TyKind::Object(param), TyKind::Interface(arg)) => {
+				for (key, param) in param.fields() {
+					if let Some(arg) = arg.get_prop(key) {
+						self.collect_candidates(*param, arg, candidates);
+					}
+				}
+			}
+			(TyKind::Interface(param), TyKind::Interface(arg)) if param.name() == arg.name() => {
+				for (param, arg) in param.type_args().iter().zip(arg.type_args()) {
+					self.collect_candidates(*param, *arg, candidates);
+				}
+			}
+			(TyKind::Interface(param), TyKind::Object(arg)) => {
+				for (key, param) in param.fields() {
+					if let Some(arg) = arg.get_prop(key) {
+						self.collect_candidates(*param, arg, candidates);
+					}
+				}
+			}
+			(TyKind::Template(param), TyKind::String(Some(_)) | TyKind::Template(_)) => {
+				self.collect_template_candidates(param, arg, candidates)
+			}
+			// e.g. 'T & U' from '{a: number, b: string}': every generic part is inferred from the whole argument
+			(TyKind::Intersection(param), _) => {
+				for param in param.parts() {
+					self.collect_candidates(*param, arg, candidates);
+				}
+			}
+			(TyKind::Union(param), _) => {
+				// e.g. 'T | undefined' from 'number | undefined': strip the arms matched by
+				//      the non-generic part and infer from the rest
+				let (generic, fixed): (Vec<Ty<'tcx>>, Vec<Ty<'tcx>>) = param
+					.arms()
+					.iter()
+					.partition(|arm| self.has_type_params(**arm));
+
+				if let [generic] = generic.as_slice() {
+					let arg_arms = match arg.kind() {
+						TyKind::Union(arg) => arg.arms().clone(),
+						_ => BTreeSet::from([arg]),
+					};
+					let rest: BTreeSet<_> = arg_arms
+						.into_iter()
+						.filter(|arm| !fixed.iter().any(|fixed| self.satisfies(*fixed, *arm)))
+						.collect();
+
+					if !rest.is_empty() {
+						self.collect_candidates(*generic, self.tcx.new_union(rest), candidates);
+					}
+				}
+			}
+			_ => {}
+		}
+	}
+
+	/// Picks the first candidate (widened) that every other candidate satisfies,
+	/// like TypeScript's "common supertype". Mismatching arguments are reported on argument checking.
+	fn resolve_candidates(&self, candidates: &[Ty<'tcx>]) -> Option<Ty<'tcx>> {
+		let first = candidates.first()?;
+
+		if candidates.iter().all(|ty| ty == first) {
+			return Some(*first);
+		}
+
+		candidates
+			.iter()
+			.map(|ty| self.widen(*ty))
+			.find(|supertype| candidates.iter().all(|ty| self.satisfies(*supertype, *ty)))
+			.or_else(|| Some(self.widen(*first)))
+	}
+
+	#[allow(clippy::only_used_in_recursion)]
+	pub fn has_type_params(&self, ty: Ty<'tcx>) -> bool {
+		match ty.kind() {
+			TyKind::TypeParameter(_) => true,
+			TyKind::Function(function) => {
+				function
+					.params
+					.iter()
+					.chain(&function.rest)
+					.any(|(_, ty)| self.has_type_params(*ty))
+					|| self.has_type_params(function.ret)
+			}
+			TyKind::Overloaded(overloaded) => overloaded
+				.signatures()
+				.iter()
+				.any(|ty| self.has_type_params(*ty)),
+			TyKind::Object(obj) => obj
+				.fields()
+				.values()
+				.chain(&obj.signatures().call)
+				.chain(&obj.signatures().construct)
+				.any(|ty| self.has_type_params(*ty)),
+			TyKind::Interface(interface) => interface
+				.type_args()
+				.iter()
+				.any(|ty| self.has_type_params(*ty)),
+			TyKind::Array(array) => self.has_type_params(array.element),
+			TyKind::Tuple(tuple) => tuple.elements.iter().any(|ty| self.has_type_params(*ty)),
+			TyKind::Union(uni) => uni.arms().iter().any(|ty| self.has_type_params(*ty)),
+			TyKind::Intersection(intersection) => intersection
+				.parts()
+				.iter()
+				.any(|ty| self.has_type_params(*ty)),
+			TyKind::Template(template) => template.types.iter().any(|ty| self.has_type_params(*ty)),
+			TyKind::StringMapping(_, ty) => self.has_type_params(*ty),
+			TyKind::KeyOf(_)
+			| TyKind::IndexedAccess(_)
+			| TyKind::Conditional(_)
+			| TyKind::Mapped(_) => true,
+			_ => false,
+		}
+	}
+}
@@ -0,0 +1,281 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use swc_atoms::Atom;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{
+	Expr, MemberExpr, MemberProp, TsEntityName, TsExprWithTypeArgs, TsInterfaceDecl,
+	TsPropertySignature, TsQualifiedName, TsType, TsTypeElement, TsTypeRef,
+};
+
+use crate::{
+	Ty, TyKind,
+	checker::errors::ErrorKind,
+	kind::{Generic, Interface, Object, Overloaded},
+	symbol::Symbol,
+};
+
+use super::BaseChecker;
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Checks an interface declaration. A declaration of an interface already declared in the scope
+	/// adds members to it, and members of the bases are inherited unless they're redeclared.
+	pub fn check_interface(&self, decl: &TsInterfaceDecl) {
+		let TsInterfaceDecl {
+			id,
+			type_params,
+			extends,
+			body,
+			span,
+			..
+		} = decl;
+		let name = Symbol::new(id.to_id());
+		let prev = self
+			.get_binding(&name)
+			.and_then(|binding| Self::declared_interface(binding.ty, &name));
+
+		let type_params = match prev {
+			Some((_, prev_params)) => {
+				let params = type_params.iter().flat_map(|decl| &decl.params);
+				if params.clone().count() != prev_params.len() {
+					self.add_error_with_span(
+						ErrorKind::InterfaceTypeParamsDiffer(name.clone()),
+						id.span,
+					);
+				}
+
+				// the declarations share the type parameters of the first one
+				for (index, param) in params.enumerate() {
+					let ty = prev_params.get(index).copied();
+					let ty = ty.unwrap_or(self.constants.err);
+					self.set_binding(&Symbol::new(param.name.to_id()), None, ty, false);
+				}
+				prev_params.to_vec()
+			}
+			None => self.bind_type_params(type_params.as_deref()),
+		};
+
+		let mut members = self.build_type_members(&body.body, *span);
+		let mut ancestors = match prev {
+			Some((prev, _)) => {
+				self.merge_interface(&mut members, prev, &body.body, id.span);
+				prev.ancestors().clone()
+			}
+			None => Default::default(),
+		};
+
+		let mut inherited = BTreeMap::new();
+		for base in extends {
+			let base_ty = self.build_ts_type(&Self::base_type(base));
+			let (fields, optional, readonly, index, signatures) = match base_ty.kind() {
+				TyKind::Object(obj) => (
+					obj.fields(),
+					obj.optional(),
+					obj.readonly(),
+					obj.index(),
+					obj.signatures(),
+				),
+				TyKind::Interface(interface) => {
+					ancestors.insert(base_ty);
+					ancestors.extend(interface.ancestors().iter().copied());
+					(
+						interface.fields(),
+						interface.optional(),
+						interface.readonly(),
+						interface.index(),
+						interface.signatures(),
+					)
+				}
+				TyKind::Err => continue,
+				_ => {
+					self.add_error_with_span(ErrorKind::InterfaceExtendsNonObject, base.span);
+					continue;
+				}
+			};
+
+			for (key, ty) in fields {
+				if let Some(declared) = members.fields.get(key) {
+					let expected = self.optional_prop_ty(*ty, optional.contains(key));
+					if !self.satisfies(expected, *declared) {
+						self.add_error_with_span(
+							ErrorKind::InterfaceIncorrectlyExtends(
+								name.clone(),
+								base_ty,
+								key.clone(),
+							),
+							id.span,
+						);
+					}
+					continue;
+				}
+
+				match inherited.get(key) {
+					Some((first, first_ty)) if first_ty != ty => {
+						self.add_error_with_span(
+							ErrorKind::InterfaceBasesConflict(
+								name.clone(),
+								*first,
+								base_ty,
+								key.clone(),
+							),
+							id.span,
+						);
+					}
+					Some(_) => {}
+					None => {
+						inherited.insert(key.clone(), (base_ty, *ty));
+						if optional.contains(key) {
+							members.optional.insert(key.clone());
+						}
+						if readonly.contains(key) {
+							members.readonly.insert(key.clone());
+						}
+					}
+				}
+			}
+
+			members.index.string = members.index.string.or(index.string);
+			members.index.number = members.index.number.or(index.number);
+			// signatures of the interface hide those of the bases
+			if members.signatures.call.is_empty() {
+				members.signatures.call = signatures.call.clone();
+			}
+			if members.signatures.construct.is_empty() {
+				members.signatures.construct = signatures.construct.clone();
+			}
+		}
+
+		members
+			.fields
+			.extend(inherited.into_iter().map(|(key, (_, ty))| (key, ty)));
+
+		let interface = self.tcx.new_interface(Rc::new(
+			Interface::new_with_type_args(name.clone(), type_params.clone(), members.fields)
+				.with_optional(members.optional)
+				.with_readonly(members.readonly)
+				.with_index(members.index)
+				.with_signatures(members.signatures)
+				.with_ancestors(ancestors),
+		));
+
+		// Generic interfaces are instantiated on reference (e.g. Box<number>)
+		let interface = match type_params.is_empty() {
+			true => interface,
+			false => self
+				.tcx
+				.new_generic(Generic::new(name.clone(), type_params, interface)),
+		};
+
+		self.set_binding(&name, None, interface, false);
+	}
+
+	/// The interface `name` declared by `ty`, with its type parameters
+	fn declared_interface<'a>(
+		ty: Ty<'a>,
+		name: &Symbol,
+	) -> Option<(&'a Interface<'a>, &'a [Ty<'a>])> {
+		let (interface, type_params) = match ty.kind() {
+			TyKind::Interface(interface) => (interface, interface.type_args()),
+			TyKind::Generic(Generic {
+				type_params, ty, ..
+			}) => match ty.kind() {
+				TyKind::Interface(interface) => (interface, type_params.as_slice()),
+				_ => return None,
+			},
+			_ => return None,
+		};
+
+		(interface.name() == name).then_some((interface.as_ref(), type_params))
+	}
+
+	/// Adds the members of an earlier declaration of the interface. Methods declared by both are
+	/// overloads, where the later signatures come first.
+	fn merge_interface(
+		&self,
+		members: &mut Object<'tcx>,
+		prev: &Interface<'tcx>,
+		body: &[TsTypeElement],
+		span: Span,
+	) {
+		for (key, prev_ty) in prev.fields() {
+			let Some(ty) = members.fields.get(key).copied() else {
+				members.fields.insert(key.clone(), *prev_ty);
+				if prev.optional().contains(key) {
+					members.optional.insert(key.clone());
+				}
+				if prev.readonly().contains(key) {
+					members.readonly.insert(key.clone());
+				}
+				continue;
+			};
+
+			let signatures = |ty: Ty<'tcx>| match ty.kind() {
+				TyKind::Function(_) => Some(vec![ty]),
+				TyKind::Overloaded(overloaded) => Some(overloaded.signatures().to_vec()),
+				_ => None,
+			};
+
+			match (signatures(ty), signatures(*prev_ty)) {
+				(Some(mut signatures), Some(prev_signatures)) => {
+					signatures.extend(prev_signatures);
+					let overloaded = self.tcx.new_overloaded(Overloaded::new(signatures));
+					members.fields.insert(key.clone(), overloaded);
+				}
+				_ if ty != *prev_ty => {
+					self.add_error_with_span(
+						ErrorKind::SubsequentPropertyType(key.clone(), *prev_ty, ty),
+						Self::property_span(body, key).unwrap_or(span),
+					);
+					members.fields.insert(key.clone(), *prev_ty);
+				}
+				_ => {}
+			}
+		}
+
+		members.index.string = members.index.string.or(prev.index().string);
+		members.index.number = members.index.number.or(prev.index().number);
+		members
+			.signatures
+			.call
+			.extend(prev.signatures().call.iter().copied());
+		members
+			.signatures
+			.construct
+			.extend(prev.signatures().construct.iter().copied());
+	}
+
+	/// The span of the property signature `key`
+	fn property_span(body: &[TsTypeElement], key: &Atom) -> Option<Span> {
+		body.iter().find_map(|member| match member {
+			TsTypeElement::TsPropertySignature(TsPropertySignature {
+				key: name, span, ..
+			}) if Self::type_member_key(name).as_ref() == Some(key) => Some(*span),
+			_ => None,
+		})
+	}
+
+	/// The type referenced by a base in an `extends` or `implements` clause, like `Box<number>` or
+	/// `ns.Base`
+	pub fn base_type(base: &TsExprWithTypeArgs) -> TsType {
+		fn entity_name(expr: &Expr) -> TsEntityName {
+			match expr {
+				Expr::Ident(ident) => TsEntityName::Ident(ident.clone()),
+				Expr::Member(MemberExpr {
+					obj,
+					prop: MemberProp::Ident(prop),
+					span,
+				}) => TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+					span: *span,
+					left: entity_name(obj),
+					right: prop.clone(),
+				})),
+				_ => todo!("{:#?}", expr),
+			}
+		}
+
+		TsType::TsTypeRef(TsTypeRef {
+			span: base.expr.span(),
+			type_name: entity_name(&base.expr),
+			type_params: base.type_args.clone(),
+		})
+	}
+}
@@ -0,0 +1,199 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use swc_atoms::Atom;
+use swc_common::Span;
+use swc_ecma_ast::{
+	Expr, Lit, Pat, TsCallSignatureDecl, TsConstructSignatureDecl, TsFnParam, TsGetterSignature,
+	TsMethodSignature, TsPropertySignature, TsSetterSignature, TsTypeAnn, TsTypeElement,
+	TsTypeParamDecl,
+};
+
+use crate::{
+	Ty, TyKind,
+	kind::{NumberLit, Object, Overloaded},
+};
+
+use super::BaseChecker;
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Builds the members of an interface or a type literal. Methods declared more than once are
+	/// overloads, and a getter without a setter is readonly.
+	pub fn build_type_members(&self, members: &[TsTypeElement], span: Span) -> Object<'tcx> {
+		let mut obj = Object::new(BTreeMap::new());
+		let mut methods = BTreeSet::new();
+		let mut setters = BTreeSet::new();
+
+		for member in members {
+			match member {
+				TsTypeElement::TsPropertySignature(TsPropertySignature {
+					key,
+					type_ann,
+					optional,
+					readonly,
+					..
+				}) => {
+					let Some(key) = Self::type_member_key(key) else {
+						continue;
+					};
+					let ty = match type_ann {
+						Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
+						None => self.constants.unknown,
+					};
+
+					if *optional {
+						obj.optional.insert(key.clone());
+					}
+					if *readonly {
+						obj.readonly.insert(key.clone());
+					}
+					obj.fields.insert(key, ty);
+				}
+				TsTypeElement::TsMethodSignature(TsMethodSignature {
+					key,
+					optional,
+					params,
+					type_ann,
+					type_params,
+					..
+				}) => {
+					let Some(key) = Self::type_member_key(key) else {
+						continue;
+					};
+					let signature =
+						self.build_signature(type_params.as_deref(), params, type_ann.as_deref());
+
+					let ty = match obj.fields.get(&key) {
+						Some(prev) if methods.contains(&key) => {
+							let mut signatures = match prev.kind() {
+								TyKind::Overloaded(overloaded) => overloaded.signatures().to_vec(),
+								_ => vec![*prev],
+							};
+							signatures.push(signature);
+							self.tcx.new_overloaded(Overloaded::new(signatures))
+						}
+						_ => signature,
+					};
+
+					if *optional {
+						obj.optional.insert(key.clone());
+					}
+					methods.insert(key.clone());
+					obj.fields.insert(key, ty);
+				}
+				TsTypeElement::TsCallSignatureDecl(TsCallSignatureDecl {
+					params,
+					type_ann,
+					type_params,
+					..
+				}) => {
+					let signature =
+						self.build_signature(type_params.as_deref(), params, type_ann.as_deref());
+					obj.signatures.call.push(signature);
+				}
+				TsTypeElement::TsConstructSignatureDecl(TsConstructSignatureDecl {
+					params,
+					type_ann,
+					type_params,
+					..
+				}) => {
+					let signature =
+						self.build_signature(type_params.as_deref(), params, type_ann.as_deref());
+					obj.signatures.construct.push(signature);
+				}
+				TsTypeElement::TsGetterSignature(TsGetterSignature { key, type_ann, .. }) => {
+					let Some(key) = Self::type_member_key(key) else {
+						continue;
+					};
+					let ty = match type_ann {
+						Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
+						None => self.constants.unknown,
+					};
+
+					if !setters.contains(&key) {
+						obj.readonly.insert(key.clone());
+					}
+					obj.fields.insert(key, ty);
+				}
+				TsTypeElement::TsSetterSignature(TsSetterSignature { key, param, .. }) => {
+					let Some(key) = Self::type_member_key(key) else {
+						continue;
+					};
+					let params = self.build_params([&Self::fn_param_to_pat(param)], &|pat| {
+						self.param_missing_type_ann(pat)
+					});
+
+					setters.insert(key.clone());
+					obj.readonly.remove(&key);
+					// the getter of the property gives its type
+					obj.fields.entry(key).or_insert(params.params[0].1);
+				}
+				TsTypeElement::TsIndexSignature(sig) => {
+					self.build_index_signature(sig, &mut obj.index);
+				}
+			}
+		}
+
+		self.check_index_signatures(&obj.fields, &obj.optional, &obj.index, span);
+		obj
+	}
+
+	/// Builds the type of a signature like `<T>(x: T): T`; like a function declaration, the return
+	/// type is `void` unless it's given
+	pub fn build_signature(
+		&self,
+		type_params: Option<&TsTypeParamDecl>,
+		params: &[TsFnParam],
+		ret: Option<&TsTypeAnn>,
+	) -> Ty<'tcx> {
+		let type_params = self.bind_type_params(type_params);
+		let ret = match ret {
+			Some(ret) => self.build_ts_type(&ret.type_ann),
+			None => self.constants.void,
+		};
+
+		let pats: Vec<_> = params.iter().map(Self::fn_param_to_pat).collect();
+		let params = self.build_params(&pats, &|pat| self.param_missing_type_ann(pat));
+
+		self.tcx
+			.new_function(params.into_function(ret).with_type_params(type_params))
+	}
+
+	/// The call (or construct) signatures of an object type as a function, overloaded if there are
+	/// several of them
+	pub fn object_signature(&self, ty: Ty<'tcx>, construct: bool) -> Option<Ty<'tcx>> {
+		let signatures = match ty.kind() {
+			TyKind::Object(obj) => obj.signatures(),
+			TyKind::Interface(interface) => interface.signatures(),
+			_ => return None,
+		};
+		let signatures = match construct {
+			true => &signatures.construct,
+			false => &signatures.call,
+		};
+
+		match signatures.as_slice() {
+			[] => None,
+			[signature] => Some(*signature),
+			_ => Some(self.tcx.new_overloaded(Overloaded::new(signatures.clone()))),
+		}
+	}
+
+	fn fn_param_to_pat(param: &TsFnParam) -> Pat {
+		match param {
+			TsFnParam::Ident(ident) => Pat::Ident(ident.clone()),
+			TsFnParam::Array(array) => Pat::Array(array.clone()),
+			TsFnParam::Rest(rest) => Pat::Rest(rest.clone()),
+			TsFnParam::Object(object) => Pat::Object(object.clone()),
+		}
+	}
+
+	/// The name of a member; computed names other than literals are not supported
+	pub(super) fn type_member_key(key: &Expr) -> Option<Atom> {
+		match key {
+			Expr::Ident(ident) => Some(ident.sym.clone()),
+			Expr::Lit(Lit::Str(str)) => Some(str.value.clone()),
+			Expr::Lit(Lit::Num(num)) => Some(Atom::new(NumberLit::new(num.value).to_string())),
+			_ => None,
+		}
+	}
+}
@@ -1,11 +1,23 @@
 mod decl;
+mod enums;
 mod expr;
+mod generic;
+mod interface;
+mod members;
 mod narrow;
+mod object_lit;
+mod overload;
+mod params;
+mod pattern;
 mod satisfies;
+mod spread;
 mod stmt;
+mod template;
 mod ts_type;
 mod widen;
 
+pub use params::Params;
+
 use std::{cell::RefCell, collections::HashMap, fmt::Debug};
 
 use crate::{
@@ -25,12 +37,24 @@ struct Binding<'tcx> {
 	is_assignable: bool,
 }
 
+/// The class whose members are being checked
+#[derive(Debug, Clone)]
+pub struct ClassScope {
+	/// The class followed by the classes it extends, whose protected members it can access
+	pub classes: Vec<Symbol>,
+	/// Whether it's the constructor, which may assign to the readonly fields of the class
+	pub is_constructor: bool,
+}
+
 pub struct BaseChecker<'tcx> {
 	pub tcx: &'tcx TyContext<'tcx>,
 	pub constants: TyConstants<'tcx>,
 	bindings: RefCell<HashMap<Symbol, Binding<'tcx>>>,
 	pub locals: RefCell<HashMap<LocalId, Value>>,
 	pub errors: RefCell<Vec<Error<'tcx>>>,
+	/// Overload signatures waiting for the implementation of their function
+	overloads: RefCell<Vec<(Symbol, Ty<'tcx>, Span)>>,
+	class_scope: RefCell<Option<ClassScope>>,
 }
 
 impl Debug for BaseChecker<'_> {
@@ -53,6 +77,8 @@ impl<'tcx> BaseChecker<'tcx> {
 			bindings: RefCell::new(HashMap::new()),
 			locals: RefCell::new(HashMap::new()),
 			errors: RefCell::new(vec![]),
+			overloads: RefCell::new(vec![]),
+			class_scope: RefCell::new(None),
 		};
 
 		// Register built-in types
@@ -93,10 +119,33 @@ impl<'tcx> BaseChecker<'tcx> {
 		for (name, binding) in vars.iter() {
 			checker.set_binding(name, binding.current, binding.ty, binding.is_assignable);
 		}
+		checker.set_class_scope(self.class_scope());
 
 		checker
 	}
 
+	pub fn class_scope(&self) -> Option<ClassScope> {
+		self.class_scope.borrow().clone()
+	}
+
+	pub fn set_class_scope(&self, scope: Option<ClassScope>) {
+		*self.class_scope.borrow_mut() = scope;
+	}
+
+	/// Binds `this` in the code of a class, both as a value and as a type
+	pub fn bind_this(&self, ty: Ty<'tcx>) {
+		let this = self.add_local(ty, Value::Param);
+		self.set_binding(&Symbol::new_this(), Some(this), ty, false);
+	}
+
+	/// The class scope of a function nested in this checker's code, which is not a constructor
+	pub fn nested_class_scope(&self) -> Option<ClassScope> {
+		self.class_scope().map(|scope| ClassScope {
+			is_constructor: false,
+			..scope
+		})
+	}
+
 	pub fn add_error_with_span(&self, err: ErrorKind<'tcx>, span: Span) {
 		self.errors.borrow_mut().push(Error::new(err, span));
 	}
@@ -151,6 +200,7 @@ impl<'tcx> BaseChecker<'tcx> {
 					.map(|(key, ty)| {
 						let widened_ty = match ty.kind() {
 							TyKind::Number(_) => self.constants.number, // 30 -> number
+							TyKind::BigInt(_) => self.constants.bigint, // 30n -> bigint
 							TyKind::Boolean(_) => self.constants.boolean, // true -> boolean
 							TyKind::String(_) => *ty,                   // Keep string literals like "Alice"
 							_ => *ty,
@@ -159,8 +209,13 @@ impl<'tcx> BaseChecker<'tcx> {
 					})
 					.collect::<BTreeMap<_, _>>();
 
-				self.tcx
-					.new_object(crate::kind::Object::new(widened_fields))
+				self.tcx.new_object(
+					crate::kind::Object::new(widened_fields)
+						.with_optional(obj.optional.clone())
+						.with_readonly(obj.readonly.clone())
+						.with_index(obj.index)
+						.with_signatures(obj.signatures.clone()),
+				)
 			}
 			_ => ty,
 		}
@@ -39,6 +39,7 @@ impl<'tcx> BaseChecker<'tcx> {
 						let narrowed_ty = match value.as_str() {
 							"boolean" => Some(self.constants.boolean),
 							"number" => Some(self.constants.number),
+							"bigint" => Some(self.constants.bigint),
 							"string" => Some(self.constants.string),
 							_ => None,
 						};
@@ -84,4 +85,22 @@ impl<'tcx> BaseChecker<'tcx> {
 
 		None
 	}
+
+	/// The arms a value of `ty` can be narrowed to by comparing it, e.g. in a switch;
+	/// an enum has one arm per member
+	pub fn narrowing_arms(&self, ty: Ty<'tcx>) -> Option<BTreeSet<Ty<'tcx>>> {
+		match ty.kind() {
+			TyKind::Enum(enum_) => Some(self.enum_members(enum_)),
+			TyKind::Union(uni) => Some(
+				uni.arms()
+					.iter()
+					.flat_map(|arm| match arm.kind() {
+						TyKind::Enum(enum_) => self.enum_members(enum_),
+						_ => BTreeSet::from([*arm]),
+					})
+					.collect(),
+			),
+			_ => None,
+		}
+	}
 }
@@ -0,0 +1,269 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use swc_atoms::Atom;
+use swc_common::Spanned;
+use swc_ecma_ast::{
+	BlockStmt, Expr, GetterProp, KeyValueProp, MethodProp, PrivateName, Prop, PropName,
+	PropOrSpread, SetterProp, SpreadElement,
+};
+
+use crate::{
+	Ty, TyKind,
+	checker::{errors::ErrorKind, function::FunctionChecker},
+	kind::{Function, NumberLit, Object, TypeParameter},
+	sir::{self, Local, Value},
+};
+
+use super::{BaseChecker, Params};
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// The key of a property; computed keys are not supported
+	pub fn prop_key(key: &PropName) -> Atom {
+		match key {
+			PropName::Ident(ident) => ident.sym.clone(),
+			PropName::Str(str) => str.value.clone(),
+			PropName::Num(num) => Atom::new(NumberLit::new(num.value).to_string()),
+			_ => todo!("{:#?}", key),
+		}
+	}
+
+	/// The key of a `#private` class member, which keeps the `#`
+	pub fn private_key(name: &PrivateName) -> Atom {
+		Atom::new(format!("#{}", name.name))
+	}
+
+	/// Checks an object literal, where a later property overrides an earlier one of the same key.
+	/// Spreading a union gives a union of objects, one for each of its arms.
+	pub(super) fn check_object_lit(
+		&self,
+		props: &[PropOrSpread],
+		expected_ty: Option<Ty<'tcx>>,
+	) -> Local<'tcx> {
+		let mut obj_tys = vec![Object::new(BTreeMap::new())];
+		let mut obj = sir::Object::new();
+		// a getter without a setter is readonly
+		let mut setters = BTreeSet::new();
+
+		for prop in props {
+			let (key, value) = match prop {
+				PropOrSpread::Spread(SpreadElement { expr, .. }) => {
+					self.spread_object(&mut obj_tys, &mut obj, expr);
+					continue;
+				}
+				PropOrSpread::Prop(prop) => match prop.as_ref() {
+					Prop::KeyValue(KeyValueProp { key, value }) => {
+						(Self::prop_key(key), self.check_expr(value, expected_ty))
+					}
+					Prop::Shorthand(ident) => (
+						ident.sym.clone(),
+						self.check_expr(&Expr::Ident(ident.clone()), expected_ty),
+					),
+					Prop::Method(MethodProp { key, function }) => {
+						let type_params = self.bind_type_params(function.type_params.as_deref());
+						let params = self
+							.build_params(function.params.iter().map(|param| &param.pat), &|pat| {
+								self.param_missing_type_ann(pat)
+							});
+						let ret = self.return_type(function.return_type.as_deref());
+
+						let method = self.check_method_body(params, ret, function.body.as_ref());
+						let ty = self.tcx.new_function(method.with_type_params(type_params));
+
+						(Self::prop_key(key), self.add_local(ty, Value::Closure()))
+					}
+					Prop::Getter(GetterProp {
+						key,
+						type_ann,
+						body,
+						..
+					}) => {
+						let ret = self.return_type(type_ann.as_deref());
+						let params = self.build_params([], &|pat| self.param_missing_type_ann(pat));
+						self.check_method_body(params, ret, body.as_ref());
+
+						let key = Self::prop_key(key);
+						if !setters.contains(&key) {
+							for obj_ty in &mut obj_tys {
+								obj_ty.readonly.insert(key.clone());
+							}
+						}
+						(key, self.add_local(ret, Value::Closure()))
+					}
+					Prop::Setter(SetterProp {
+						key, param, body, ..
+					}) => {
+						let params = self.build_params([param.as_ref()], &|pat| {
+							self.param_missing_type_ann(pat)
+						});
+						let ty = params.params[0].1;
+						self.check_method_body(params, self.constants.void, body.as_ref());
+
+						let key = Self::prop_key(key);
+						setters.insert(key.clone());
+						// the getter of the property gives its type
+						let mut has_getter = true;
+						for obj_ty in &mut obj_tys {
+							obj_ty.readonly.remove(&key);
+							has_getter &= obj_ty.fields.contains_key(&key);
+						}
+						if has_getter {
+							continue;
+						}
+						(key, self.add_local(ty, Value::Closure()))
+					}
+					Prop::Assign(_) => unreachable!("only in patterns"),
+				},
+			};
+
+			for obj_ty in &mut obj_tys {
+				obj_ty.optional.remove(&key);
+				obj_ty.fields.insert(key.clone(), value.ty);
+			}
+			obj.fields.push((key, value.id));
+		}
+
+		let obj_tys = obj_tys
+			.into_iter()
+			.map(|obj_ty| self.tcx.new_object(obj_ty))
+			.collect();
+		self.add_local(self.tcx.new_union(obj_tys), Value::Obj(obj))
+	}
+
+	/// Adds the properties of `...expr` to each object of an object literal
+	fn spread_object(&self, obj_tys: &mut Vec<Object<'tcx>>, obj: &mut sir::Object, expr: &Expr) {
+		let spread = self.check_expr(expr, None);
+
+		let mut arms = vec![];
+		if !self.spread_arms(spread.ty, &mut arms) {
+			self.add_error_with_span(ErrorKind::SpreadTypesFromObjectTypes, expr.span());
+			return;
+		}
+		// spreading only `null` or `undefined`
+		if arms.is_empty() {
+			return;
+		}
+
+		let mut keys = BTreeSet::new();
+		*obj_tys = std::mem::take(obj_tys)
+			.into_iter()
+			.flat_map(|obj_ty| {
+				arms.iter().map(move |arm| {
+					let mut obj_ty = obj_ty.clone();
+					if let Some(arm) = arm {
+						self.spread_members(&mut obj_ty, *arm);
+					}
+					obj_ty
+				})
+			})
+			.collect();
+
+		for arm in arms.into_iter().flatten() {
+			let fields = match arm.kind() {
+				TyKind::Object(spread_obj) => spread_obj.fields(),
+				TyKind::Interface(interface) => interface.fields(),
+				_ => unreachable!("spread arms are objects or interfaces"),
+			};
+			for (key, ty) in fields {
+				if keys.insert(key.clone()) {
+					let value = self.add_local(*ty, Value::Member(spread.id, key.clone()));
+					obj.fields.push((key.clone(), value.id));
+				}
+			}
+		}
+	}
+
+	/// Collects the types `ty` spreads as, `None` for one whose members are not tracked.
+	/// `null` and `undefined` spread nothing; `false` if `ty` can't be spread.
+	#[allow(clippy::only_used_in_recursion)]
+	fn spread_arms(&self, ty: Ty<'tcx>, arms: &mut Vec<Option<Ty<'tcx>>>) -> bool {
+		match ty.kind() {
+			TyKind::Object(_) | TyKind::Interface(_) => arms.push(Some(ty)),
+			TyKind::Null | TyKind::Undefined => {}
+			TyKind::Err
+			| TyKind::Array(_)
+			| TyKind::Tuple(_)
+			| TyKind::Function(_)
+			| TyKind::Overloaded(_) => arms.push(None),
+			TyKind::Union(uni) => {
+				return uni.arms().iter().all(|arm| self.spread_arms(*arm, arms));
+			}
+			TyKind::TypeParameter(TypeParameter {
+				constraint: Some(constraint),
+				..
+			}) => return self.spread_arms(*constraint, arms),
+			_ => return false,
+		}
+
+		true
+	}
+
+	/// Adds the members of a spread object or interface. An optional one keeps the type of
+	/// the property it may override.
+	fn spread_members(&self, obj_ty: &mut Object<'tcx>, spread: Ty<'tcx>) {
+		let (fields, optional) = match spread.kind() {
+			TyKind::Object(spread_obj) => (spread_obj.fields(), spread_obj.optional()),
+			TyKind::Interface(interface) => (interface.fields(), interface.optional()),
+			_ => unreachable!("spread arms are objects or interfaces"),
+		};
+
+		for (key, ty) in fields {
+			let ty = match obj_ty.fields.get(key) {
+				Some(existing) if optional.contains(key) => {
+					self.tcx.new_union(BTreeSet::from([*existing, *ty]))
+				}
+				_ => {
+					if optional.contains(key) {
+						obj_ty.optional.insert(key.clone());
+					} else {
+						obj_ty.optional.remove(key);
+					}
+					*ty
+				}
+			};
+
+			obj_ty.readonly.remove(key);
+			obj_ty.fields.insert(key.clone(), ty);
+		}
+	}
+
+	/// The declared return type; seal doesn't infer it
+	fn return_type(&self, type_ann: Option<&swc_ecma_ast::TsTypeAnn>) -> Ty<'tcx> {
+		match type_ann {
+			Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
+			None => self.constants.void,
+		}
+	}
+
+	/// Checks the body of a method of an object literal, which sees the bindings around it
+	fn check_method_body(
+		&self,
+		params: Params<'tcx>,
+		ret: Ty<'tcx>,
+		body: Option<&BlockStmt>,
+	) -> Function<'tcx> {
+		let Some(body) = body else {
+			return params.into_function(ret);
+		};
+
+		let checker = FunctionChecker::new(self.tcx, params, ret);
+		checker.set_class_scope(self.nested_class_scope());
+
+		for (name, var) in self.bindings.borrow().iter() {
+			// the parameters shadow the bindings around
+			if checker.get_binding(name).is_some() {
+				continue;
+			}
+
+			let param = checker.add_local(var.ty, Value::Param);
+			checker.set_binding(name, Some(param), var.ty, var.is_assignable);
+		}
+
+		let result = checker.check_body(body);
+
+		for error in result.errors {
+			self.add_error_with_span(error.kind, error.span);
+		}
+
+		result.ty
+	}
+}
@@ -0,0 +1,152 @@
+use swc_common::Span;
+use swc_ecma_ast::{Decl, FnDecl, Stmt};
+
+use crate::{
+	Ty, TyKind,
+	checker::errors::ErrorKind,
+	kind::{Function, Overloaded},
+	symbol::Symbol,
+};
+
+use super::{BaseChecker, spread::Args};
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Holds an overload signature until the implementation that follows it
+	pub fn add_overload(&self, name: Symbol, signature: Ty<'tcx>, span: Span) {
+		self.overloads.borrow_mut().push((name, signature, span));
+	}
+
+	/// Reports the overload signatures which are not followed by an implementation
+	pub fn finish_overloads(&self) {
+		for (_, _, span) in self.overloads.borrow_mut().drain(..) {
+			self.add_error_with_span(ErrorKind::FunctionImplementationMissing, span);
+		}
+	}
+
+	/// Finishes the pending overloads unless `stmt` continues them
+	pub(super) fn finish_overloads_before(&self, stmt: &Stmt) {
+		let continues = match (self.overloads.borrow().last(), stmt) {
+			(None, _) => return,
+			(Some((name, _, _)), Stmt::Decl(Decl::Fn(FnDecl { ident, .. }))) => {
+				*name == Symbol::new(ident.to_id())
+			}
+			_ => false,
+		};
+
+		if !continues {
+			self.finish_overloads();
+		}
+	}
+
+	/// Checks the pending overloads of `name` against their implementation and returns the type
+	/// to bind, i.e. the overload signatures; the implementation itself is not callable
+	pub fn merge_overloads(&self, name: &Symbol, implementation: Ty<'tcx>) -> Ty<'tcx> {
+		let overloads: Vec<_> = self.overloads.borrow_mut().drain(..).collect();
+		let TyKind::Function(implementation_fn) = implementation.kind() else {
+			unreachable!()
+		};
+
+		let mut signatures = vec![];
+		for (overload_name, signature, span) in overloads {
+			if overload_name != *name {
+				self.add_error_with_span(ErrorKind::FunctionImplementationMissing, span);
+				continue;
+			}
+
+			let TyKind::Function(overload) = signature.kind() else {
+				unreachable!()
+			};
+			if !self.is_overload_compatible(overload, implementation_fn) {
+				self.add_error_with_span(ErrorKind::OverloadNotCompatible, span);
+			}
+
+			signatures.push(signature);
+		}
+
+		match signatures.len() {
+			0 => implementation,
+			1 => signatures[0],
+			_ => self.tcx.new_overloaded(Overloaded::new(signatures)),
+		}
+	}
+
+	/// Like TypeScript, an overload is compatible when the implementation accepts its parameters
+	/// and the return types are related in either direction, unless the overload returns `void`
+	fn is_overload_compatible(
+		&self,
+		overload: &Function<'tcx>,
+		implementation: &Function<'tcx>,
+	) -> bool {
+		let related = |a: Ty<'tcx>, b: Ty<'tcx>| {
+			// type parameters are not compared across signatures
+			self.has_type_params(a)
+				|| self.has_type_params(b)
+				|| self.satisfies(a, b)
+				|| self.satisfies(b, a)
+		};
+
+		let positions = overload.params.len().max(implementation.params.len()) + 1;
+
+		implementation.min_args() <= overload.max_args().unwrap_or(usize::MAX)
+			&& (0..positions)
+				.map_while(|index| {
+					Some((overload.param_at(index)?, implementation.param_at(index)?))
+				})
+				.all(|((_, overload), (_, implementation))| related(overload, implementation))
+			&& (matches!(overload.ret.kind(), TyKind::Void)
+				|| related(overload.ret, implementation.ret))
+	}
+
+	/// Resolves a call against the overload signatures in order. When none matches, reports the
+	/// error of the closest one: the one accepting the number of arguments with the most
+	/// assignable arguments.
+	pub(super) fn resolve_overloads(
+		&self,
+		overloaded: &Overloaded<'tcx>,
+		type_args: Option<&[Ty<'tcx>]>,
+		args: &Args<'tcx>,
+		span: Span,
+	) -> Ty<'tcx> {
+		// (arity matches, assignable arguments), the index, the return type and the first error
+		let mut closest: Option<((bool, usize), usize, Ty<'tcx>, ErrorKind<'tcx>)> = None;
+
+		for (index, signature) in overloaded.signatures().iter().enumerate() {
+			let TyKind::Function(function) = signature.kind() else {
+				unreachable!()
+			};
+
+			let (ret, errors) = self.check_call(function, type_args, args, span);
+			if errors.is_empty() {
+				return ret;
+			}
+
+			let arity = self.check_args_arity(function, args).is_none();
+			let passed = (0..args.fixed.len())
+				.filter(|index| function.param_at(*index).is_some())
+				.count();
+			// an arity error comes first and is not about an argument
+			let score = (
+				arity,
+				(passed + usize::from(!arity)).saturating_sub(errors.len()),
+			);
+			let error = errors.into_iter().next().unwrap();
+
+			if closest.as_ref().is_none_or(|(best, ..)| score > *best) {
+				closest = Some((score, index, ret, error));
+			}
+		}
+
+		let (_, index, ret, error) = closest.unwrap();
+		self.add_error_with_span(
+			ErrorKind::NoOverloadMatches(
+				index,
+				overloaded.signatures().len(),
+				overloaded.signatures()[index],
+				Box::new(error),
+			),
+			span,
+		);
+
+		ret
+	}
+}
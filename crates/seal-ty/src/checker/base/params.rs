@@ -0,0 +1,178 @@
+use swc_atoms::Atom;
+use swc_common::{Spanned, SyntaxContext};
+use swc_ecma_ast::{ArrayPat, AssignPat, BindingIdent, ObjectPat, Pat, RestPat};
+
+use crate::{Ty, TyKind, checker::errors::ErrorKind, kind::Function, sir::Value, symbol::Symbol};
+
+use super::BaseChecker;
+
+/// The parameters of a signature being declared
+#[derive(Debug, Clone)]
+pub struct Params<'tcx> {
+	pub params: Vec<(Symbol, Ty<'tcx>)>,
+	/// The number of leading parameters which are neither `x?: T` nor `x: T = init`
+	pub required: usize,
+	pub rest: Option<(Symbol, Ty<'tcx>)>,
+	/// Every parameter with the type it has in the body, where `x?: T` may be `undefined`
+	bindings: Vec<(Symbol, Ty<'tcx>)>,
+}
+
+impl<'tcx> Params<'tcx> {
+	pub fn bindings(&self) -> &[(Symbol, Ty<'tcx>)] {
+		&self.bindings
+	}
+
+	pub fn into_function(self, ret: Ty<'tcx>) -> Function<'tcx> {
+		Function::new(self.params, ret)
+			.with_required(self.required)
+			.with_rest(self.rest)
+	}
+}
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Builds the parameters of a signature: `x?: T` and `x: T = init` are optional, and a
+	/// trailing `...rest: T[]` takes the remaining arguments. `missing` gives the type of a
+	/// parameter without a type annotation.
+	pub fn build_params<'a>(
+		&self,
+		pats: impl IntoIterator<Item = &'a Pat>,
+		missing: &dyn Fn(&Pat) -> Ty<'tcx>,
+	) -> Params<'tcx> {
+		let mut params = vec![];
+		let mut required = None;
+		let mut rest = None;
+		let mut bindings = vec![];
+
+		let annotated = |pat: &Pat| {
+			Self::pat_type_ann(pat).map(|type_ann| self.build_ts_type(&type_ann.type_ann))
+		};
+
+		for (index, pat) in pats.into_iter().enumerate() {
+			// the parameter, which may be a destructuring pattern, and whether it is optional
+			let (target, ty, optional) = match pat {
+				Pat::Ident(BindingIdent { id, .. }) => (
+					pat,
+					annotated(pat).unwrap_or_else(|| missing(pat)),
+					id.optional,
+				),
+				Pat::Array(ArrayPat { optional, .. }) | Pat::Object(ObjectPat { optional, .. }) => {
+					(
+						pat,
+						annotated(pat).unwrap_or_else(|| missing(pat)),
+						*optional,
+					)
+				}
+				Pat::Assign(AssignPat { left, right, .. }) => {
+					let ty = annotated(left);
+
+					// the default value sees the parameters before it
+					let scope = self.new_scoped_checker();
+					for (name, ty) in &bindings {
+						let param = scope.add_local(*ty, Value::Param);
+						scope.set_binding(name, Some(param), *ty, false);
+					}
+					let init = scope.check_expr(right, ty);
+					self.errors.borrow_mut().extend(scope.errors.into_inner());
+
+					// without an annotation, the type is inferred from the default value
+					let ty = match ty {
+						Some(ty) => {
+							if !self.satisfies(ty, init.ty) {
+								self.raise_type_error(ty, init.ty, right.span());
+							}
+							ty
+						}
+						None => self.widen(init.ty),
+					};
+
+					(left.as_ref(), ty, true)
+				}
+				Pat::Rest(RestPat { arg, .. }) => {
+					let ty = annotated(pat)
+						.or_else(|| annotated(arg))
+						.unwrap_or_else(|| missing(arg));
+
+					if !matches!(ty.kind(), TyKind::Array(_) | TyKind::Tuple(_) | TyKind::Err) {
+						self.add_error_with_span(ErrorKind::RestParamNotArray, pat.span());
+					}
+
+					self.bind_param(arg, ty, &mut bindings);
+					rest = Some((Self::param_name(arg, index), ty));
+					continue;
+				}
+				_ => todo!("{:#?}", pat),
+			};
+
+			match (optional, required) {
+				(true, None) => required = Some(params.len()),
+				(false, Some(_)) => {
+					self.add_error_with_span(ErrorKind::RequiredParamAfterOptional, pat.span());
+				}
+				_ => {}
+			}
+
+			self.bind_param(target, ty, &mut bindings);
+			params.push((Self::param_name(target, index), ty));
+		}
+
+		Params {
+			required: required.unwrap_or(params.len()),
+			params,
+			rest,
+			bindings,
+		}
+	}
+
+	/// Reports a parameter of a declaration without a type annotation
+	pub fn param_missing_type_ann(&self, pat: &Pat) -> Ty<'tcx> {
+		self.add_error_with_span(ErrorKind::ParamMissingTypeAnn, pat.span());
+		self.constants.err
+	}
+
+	/// The names a parameter binds in the body; `x?: T` may also be `undefined`
+	fn bind_param(&self, pat: &Pat, ty: Ty<'tcx>, bindings: &mut Vec<(Symbol, Ty<'tcx>)>) {
+		match pat {
+			Pat::Ident(ident) => bindings.push((
+				Symbol::new(ident.to_id()),
+				self.optional_prop_ty(ty, ident.optional),
+			)),
+			_ => {
+				let param = self.add_local(ty, Value::Param);
+				self.destructure(pat, param, &mut |ident, local| {
+					bindings.push((Symbol::new(ident.to_id()), local.ty))
+				});
+			}
+		}
+	}
+
+	/// The name of a parameter in its signature; like TypeScript, a destructured one is `__{index}`
+	fn param_name(pat: &Pat, index: usize) -> Symbol {
+		match pat {
+			Pat::Ident(ident) => Symbol::new(ident.to_id()),
+			_ => Symbol::new((Atom::new(format!("__{index}")), SyntaxContext::empty())),
+		}
+	}
+
+	/// The arity error of a call passing `len` arguments, if any
+	pub fn check_arity(&self, function: &Function<'tcx>, len: usize) -> Option<ErrorKind<'tcx>> {
+		let min = function.min_args();
+
+		match function.max_args() {
+			Some(max) if len < min || len > max => Some(ErrorKind::WrongNumArgs(min..=max, len)),
+			None if len < min => Some(ErrorKind::TooFewArgs(min, len)),
+			_ => None,
+		}
+	}
+
+	/// Whether an argument satisfies the parameter at `index`; optional parameters also take `undefined`
+	pub fn satisfies_param(&self, function: &Function<'tcx>, index: usize, arg: Ty<'tcx>) -> bool {
+		let Some((_, param)) = function.param_at(index) else {
+			return true;
+		};
+
+		self.satisfies(param, arg)
+			|| (function.required <= index
+				&& index < function.params.len()
+				&& self.satisfies(self.constants.undefined, arg))
+	}
+}
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+
+use swc_atoms::Atom;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{
+	ArrayPat, AssignPat, AssignPatProp, BindingIdent, Expr, KeyValuePatProp, ObjectPat,
+	ObjectPatProp, Pat, RestPat, TsTypeAnn,
+};
+
+use crate::{
+	Ty, TyKind,
+	checker::errors::ErrorKind,
+	kind::{NumberLit, Object},
+	sir::{Local, Value},
+	symbol::Symbol,
+};
+
+use super::BaseChecker;
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Destructures `value` by `pat`, calling `bind` with each name the pattern binds and its value.
+	/// In an assignment, a pattern may also assign to other targets like `obj.prop`.
+	pub fn destructure(
+		&self,
+		pat: &Pat,
+		value: Local<'tcx>,
+		bind: &mut dyn FnMut(&BindingIdent, Local<'tcx>),
+	) {
+		match pat {
+			Pat::Ident(ident) => bind(ident, value),
+			Pat::Assign(AssignPat { left, right, .. }) => {
+				let value = self.destructure_default(value, right);
+				self.destructure(left, value, bind);
+			}
+			Pat::Object(ObjectPat { props, span, .. }) => {
+				self.destructure_object(props, value, *span, bind)
+			}
+			Pat::Array(ArrayPat { elems, span, .. }) => {
+				self.destructure_array(elems, value, *span, bind)
+			}
+			Pat::Expr(expr) => {
+				let target = self.check_expr(expr, None);
+				if !self.satisfies(target.ty, value.ty) {
+					self.raise_type_error(target.ty, value.ty, expr.span());
+				}
+			}
+			_ => todo!("{:#?}", pat),
+		}
+	}
+
+	/// The type annotation of a binding pattern, e.g. of a parameter
+	pub fn pat_type_ann(pat: &Pat) -> Option<&TsTypeAnn> {
+		match pat {
+			Pat::Ident(BindingIdent { type_ann, .. })
+			| Pat::Array(ArrayPat { type_ann, .. })
+			| Pat::Object(ObjectPat { type_ann, .. })
+			| Pat::Rest(RestPat { type_ann, .. }) => type_ann.as_deref(),
+			Pat::Assign(AssignPat { left, .. }) => Self::pat_type_ann(left),
+			_ => None,
+		}
+	}
+
+	pub(super) fn destructure_object(
+		&self,
+		props: &[ObjectPatProp],
+		value: Local<'tcx>,
+		span: Span,
+		bind: &mut dyn FnMut(&BindingIdent, Local<'tcx>),
+	) {
+		let mut taken = vec![];
+
+		for prop in props {
+			match prop {
+				ObjectPatProp::KeyValue(KeyValuePatProp { key, value: pat }) => {
+					let span = key.span();
+					let key = Self::prop_key(key);
+
+					let prop = self.destructure_prop(value, key.clone(), span);
+					taken.push(key);
+					self.destructure(pat, prop, bind);
+				}
+				ObjectPatProp::Assign(AssignPatProp {
+					key,
+					value: default,
+					span,
+				}) => {
+					let prop = self.destructure_prop(value, key.sym.clone(), *span);
+					let prop = match default {
+						Some(default) => self.destructure_default(prop, default),
+						None => prop,
+					};
+
+					taken.push(key.sym.clone());
+					bind(key, prop);
+				}
+				ObjectPatProp::Rest(RestPat { arg, .. }) => {
+					let rest = self.add_local(
+						self.object_rest(value.ty, &taken, span),
+						Value::Member(value.id, Atom::new("rest")),
+					);
+					self.destructure(arg, rest, bind);
+				}
+			}
+		}
+	}
+
+	pub(super) fn destructure_array(
+		&self,
+		elems: &[Option<Pat>],
+		value: Local<'tcx>,
+		span: Span,
+		bind: &mut dyn FnMut(&BindingIdent, Local<'tcx>),
+	) {
+		let value = match value.ty.kind() {
+			TyKind::Array(_) | TyKind::Tuple(_) | TyKind::Err => value,
+			_ => {
+				self.add_error_with_span(ErrorKind::NotArrayType(value.ty), span);
+				self.add_local(self.constants.err, Value::Err)
+			}
+		};
+
+		for (index, elem) in elems.iter().enumerate() {
+			let Some(elem) = elem else {
+				continue;
+			};
+
+			if let Pat::Rest(RestPat { arg, .. }) = elem {
+				let rest_ty = match value.ty.kind() {
+					TyKind::Tuple(tuple) => self
+						.tcx
+						.new_tuple(tuple.elements.iter().skip(index).copied().collect()),
+					TyKind::Array(array) => self.tcx.new_array(array.element),
+					_ => self.constants.err,
+				};
+				let rest = self.add_local(rest_ty, Value::Member(value.id, Atom::new("rest")));
+				self.destructure(arg, rest, bind);
+				continue;
+			}
+
+			let element = match value.ty.kind() {
+				TyKind::Err => self.add_local(self.constants.err, Value::Err),
+				_ => {
+					let index = self.add_local(
+						self.tcx.new_const_number(index as f64),
+						Value::Number(NumberLit::new(index as f64)),
+					);
+					self.handle_computed_access(value, index, elem.span())
+				}
+			};
+			self.destructure(elem, element, bind);
+		}
+	}
+
+	/// Assigns a part of a destructured value to a variable, e.g. `a` in `[a, b] = [b, a]`
+	pub(super) fn assign_destructured(&self, ident: &BindingIdent, value: Local<'tcx>) {
+		let name = Symbol::new(ident.to_id());
+		let Some(binding) = self.get_binding(&name) else {
+			self.add_error_with_span(ErrorKind::CannotFindName(name), ident.span);
+			return;
+		};
+
+		if !binding.is_assignable {
+			self.add_error_with_span(ErrorKind::CannotAssignToConst(name.clone()), ident.span);
+		}
+
+		let ty = match binding.ty.kind() {
+			// the first assignment of a variable declared without a type
+			TyKind::Lazy => self.widen(value.ty),
+			_ => {
+				if !self.satisfies(binding.ty, value.ty) {
+					self.raise_type_error(binding.ty, value.ty, ident.span);
+				}
+				binding.ty
+			}
+		};
+
+		self.set_binding(&name, Some(value), ty, true);
+	}
+
+	/// A property taken by an object pattern; nothing is reported for an erroneous value
+	fn destructure_prop(&self, value: Local<'tcx>, key: Atom, span: Span) -> Local<'tcx> {
+		match value.ty.kind() {
+			TyKind::Err => self.add_local(self.constants.err, Value::Err),
+			_ => self.handle_property_access(value, key, span),
+		}
+	}
+
+	/// A default value replaces `undefined`, so it must fit the rest of the value's type
+	fn destructure_default(&self, value: Local<'tcx>, default: &Expr) -> Local<'tcx> {
+		let defined = match value.ty.kind() {
+			TyKind::Union(uni) => self.tcx.new_union(
+				uni.arms()
+					.iter()
+					.copied()
+					.filter(|arm| *arm != self.constants.undefined)
+					.collect(),
+			),
+			_ => value.ty,
+		};
+		let actual = self.check_expr(default, Some(defined));
+
+		let ty = match defined.kind() {
+			TyKind::Err | TyKind::Undefined => self.widen(actual.ty),
+			_ => {
+				if !self.satisfies(defined, actual.ty) {
+					self.raise_type_error(defined, actual.ty, default.span());
+				}
+				defined
+			}
+		};
+
+		Local { id: value.id, ty }
+	}
+
+	/// The properties left for `...rest` in an object pattern
+	fn object_rest(&self, ty: Ty<'tcx>, taken: &[Atom], span: Span) -> Ty<'tcx> {
+		let (fields, optional) = match ty.kind() {
+			TyKind::Object(obj) => (obj.fields(), obj.optional()),
+			TyKind::Interface(interface) => (interface.fields(), interface.optional()),
+			TyKind::Err => return ty,
+			_ => {
+				self.add_error_with_span(ErrorKind::RestTypesFromObjectTypes, span);
+				return self.constants.err;
+			}
+		};
+
+		let fields: BTreeMap<_, _> = fields
+			.iter()
+			.filter(|(key, _)| !taken.contains(key))
+			.map(|(key, ty)| (key.clone(), *ty))
+			.collect();
+		let optional = optional
+			.iter()
+			.filter(|key| fields.contains_key(*key))
+			.cloned()
+			.collect();
+
+		self.tcx
+			.new_object(Object::new(fields).with_optional(optional))
+	}
+}
@@ -1,4 +1,11 @@
-use crate::Ty;
+use std::collections::{BTreeMap, BTreeSet};
+
+use swc_atoms::Atom;
+
+use crate::{
+	Ty, TyKind,
+	kind::{Class, Function, IndexSignatures, Signatures},
+};
 
 use super::BaseChecker;
 
@@ -7,7 +14,7 @@ impl<'tcx> BaseChecker<'tcx> {
 	pub fn satisfies(&self, expected: Ty<'tcx>, actual: Ty<'tcx>) -> bool {
 		use crate::TyKind::*;
 
-		let result = match (expected.kind(), actual.kind()) {
+		match (expected.kind(), actual.kind()) {
 			// to prevent cascading errors
 			(Err, _) | (_, Err) => true,
 			// never type is the bottom type - nothing can be assigned to it except never itself
@@ -27,11 +34,58 @@ impl<'tcx> BaseChecker<'tcx> {
 			(String(None), String(_)) => true,
 			// - any const number can satisfy 'number', not vice versa
 			(Number(None), Number(_)) => true,
+			// - any const bigint can satisfy 'bigint', not vice versa
+			(BigInt(None), BigInt(_)) => true,
 			// - any const boolean can satisfy 'boolean', not vice versa
 			(Boolean(None), Boolean(_)) => true,
+			// template literal types are strings matching a pattern
+			(String(None), Template(_) | StringMapping(_, _)) => true,
+			(Template(expected), String(Some(_)) | Template(_)) => {
+				self.satisfies_template(expected, actual)
+			}
+			(StringMapping(mapping, expected), String(Some(value))) => {
+				mapping.apply(value) == value.as_ref() && self.satisfies(*expected, actual)
+			}
+
+			// enums are nominal: an enum only accepts its own members, and numeric enums numbers
+			(Enum(_), Enum(_)) | (EnumMember(_), EnumMember(_)) => expected == actual,
+			(Enum(expected), EnumMember(member)) => member.parent == *expected,
+			(Enum(expected), Number(None)) => expected
+				.members()
+				.iter()
+				.any(|(_, ty)| matches!(ty.kind(), Number(_))),
+			(Enum(expected), Number(Some(_))) => expected
+				.members()
+				.iter()
+				.any(|(_, ty)| self.satisfies(*ty, actual)),
+			(EnumMember(expected), Number(Some(_))) => expected.value() == actual,
+			// elsewhere, an enum is the union of its members
+			(_, Enum(actual)) if !matches!(expected.kind(), Enum(_) | EnumMember(_)) => self
+				.enum_members(actual)
+				.into_iter()
+				.all(|member| self.satisfies(expected, member)),
 
 			(Function(expected), Function(actual)) => {
-				if expected.params.len() != actual.params.len() {
+				// A generic function is compared by its instantiation: with the expected side's
+				// type parameters if both are generic, or with the types inferred from the expected params
+				let instantiated;
+				let actual = if actual.is_generic() {
+					let type_args = if expected.type_params.len() == actual.type_params.len() {
+						expected.type_params.clone()
+					} else {
+						let expected_params: Vec<_> =
+							expected.params.iter().map(|(_, ty)| *ty).collect();
+						self.infer_type_args(&actual.type_params, &actual.params, &expected_params)
+					};
+
+					instantiated = self.instantiate_function(actual, &type_args);
+					&instantiated
+				} else {
+					actual
+				};
+
+				// a function may ignore the arguments it is given, but must be given those it requires
+				if actual.min_args() > expected.max_args().unwrap_or(usize::MAX) {
 					return false;
 				}
 
@@ -39,13 +93,43 @@ impl<'tcx> BaseChecker<'tcx> {
 					return false;
 				}
 
-				for ((_, expected), (_, actual)) in expected.params.iter().zip(&actual.params) {
-					if !self.satisfies(*expected, *actual) {
-						return false;
-					}
-				}
+				let positions = expected.params.len().max(actual.params.len()) + 1;
+				(0..positions)
+					.map_while(|index| Some((expected.param_at(index)?, actual.param_at(index)?)))
+					.all(|((_, expected), (_, actual))| self.satisfies(actual, expected))
+			}
+			// an overloaded function is accepted where one of its signatures is,
+			// and accepts what satisfies all of them
+			(Function(_), Overloaded(actual)) => actual
+				.signatures()
+				.iter()
+				.any(|actual| self.satisfies(expected, *actual)),
+			(Overloaded(expected), _) => expected
+				.signatures()
+				.iter()
+				.all(|expected| self.satisfies(*expected, actual)),
+			// an object type with call signatures is a function
+			(Function(_), Object(_) | Interface(_)) => self
+				.object_signature(actual, false)
+				.is_some_and(|callable| self.satisfies(expected, callable)),
+			(Object(_) | Interface(_), Function(_) | Overloaded(_) | Class(_)) => {
+				let (fields, optional, signatures) = match expected.kind() {
+					Object(obj) => (obj.fields(), obj.optional(), obj.signatures()),
+					Interface(interface) => (
+						interface.fields(),
+						interface.optional(),
+						interface.signatures(),
+					),
+					_ => unreachable!(),
+				};
 
-				true
+				let satisfies_signatures = match signatures.is_empty() {
+					// a class is also an object of its static members
+					true => matches!(actual.kind(), Class(_)),
+					false => self.satisfies_signatures(signatures, actual),
+				};
+
+				satisfies_signatures && self.satisfies_members(fields, optional, actual)
 			}
 			(Union(expected), Union(actual)) => actual.arms().iter().all(|actual| {
 				expected
@@ -56,12 +140,41 @@ impl<'tcx> BaseChecker<'tcx> {
 			(Union(expected), _) => expected.arms().iter().any(|ty| self.satisfies(*ty, actual)),
 			(_, Union(actual)) => actual.arms().iter().all(|ty| self.satisfies(expected, *ty)),
 
-			(Array(expected), Array(actual)) => self.satisfies(expected.element, actual.element),
+			(Intersection(expected), _) => expected
+				.parts()
+				.iter()
+				.all(|ty| self.satisfies(*ty, actual)),
+			// either a single part satisfies the expected type, or the members of all parts together do
+			(_, Intersection(actual_intersection)) => {
+				if actual_intersection
+					.parts()
+					.iter()
+					.any(|ty| self.satisfies(expected, *ty))
+				{
+					return true;
+				}
+
+				match expected.kind() {
+					Interface(interface) => {
+						self.satisfies_members(interface.fields(), interface.optional(), actual)
+					}
+					Object(obj) => self.satisfies_members(obj.fields(), obj.optional(), actual),
+					_ => false,
+				}
+			}
+
+			// a readonly array can't be used where a mutable one is expected
+			(Array(expected), Array(actual)) => {
+				(expected.readonly || !actual.readonly)
+					&& self.satisfies(expected.element, actual.element)
+			}
 
 			// Tuple type compatibility
 			(Tuple(expected), Tuple(actual)) => {
 				// Tuples must have same length and each element must satisfy
-				if expected.elements.len() != actual.elements.len() {
+				if expected.elements.len() != actual.elements.len()
+					|| (actual.readonly && !expected.readonly)
+				{
 					return false;
 				}
 
@@ -76,52 +189,81 @@ impl<'tcx> BaseChecker<'tcx> {
 				true
 			}
 
+			// an array is an object with a number index signature of its elements
+			(Object(_) | Interface(_), Array(_) | Tuple(_)) => {
+				let (fields, optional, index, signatures) = match expected.kind() {
+					Object(obj) => (obj.fields(), obj.optional(), obj.index(), obj.signatures()),
+					Interface(interface) => (
+						interface.fields(),
+						interface.optional(),
+						interface.index(),
+						interface.signatures(),
+					),
+					_ => unreachable!(),
+				};
+				let element = match actual.kind() {
+					Array(array) => array.element,
+					Tuple(tuple) if tuple.elements.is_empty() => self.constants.never,
+					Tuple(tuple) => self.tcx.new_union(tuple.elements.iter().copied().collect()),
+					_ => unreachable!(),
+				};
+
+				signatures.is_empty()
+					&& index.string.is_none()
+					&& index
+						.number
+						.is_none_or(|expected| self.satisfies(expected, element))
+					&& self.satisfies_members(fields, optional, actual)
+			}
+
 			// Object literal should satisfy interface through structural typing
-			(Interface(expected), Object(actual_obj)) => {
+			(Interface(expected_interface), Object(_)) => {
 				// Check if object literal has all properties of interface
-				for (prop, expected_ty) in expected.fields() {
-					match actual_obj.get_prop(prop) {
-						Some(actual_ty) => {
-							if !self.satisfies(*expected_ty, actual_ty) {
-								return false;
-							}
-						}
-						None => return false,
-					}
-				}
-				true
+				self.satisfies_visibility(expected_interface.fields(), expected, actual)
+					&& self.satisfies_members(
+						expected_interface.fields(),
+						expected_interface.optional(),
+						actual,
+					) && self.satisfies_index(expected_interface.index(), actual)
+					&& self.satisfies_signatures(expected_interface.signatures(), actual)
+			}
+			(Object(expected_obj), Interface(_)) => {
+				self.satisfies_visibility(expected_obj.fields(), expected, actual)
+					&& self.satisfies_members(
+						expected_obj.fields(),
+						expected_obj.optional(),
+						actual,
+					) && self.satisfies_index(expected_obj.index(), actual)
+					&& self.satisfies_signatures(expected_obj.signatures(), actual)
 			}
 
 			// Interface inheritance checking
 			(Interface(expected_interface), Interface(actual_interface)) => {
 				// First check if names match - this handles exact type matches
-				if expected_interface.name() == actual_interface.name() {
+				// (instantiations of a generic interface with different type arguments are compared structurally)
+				if expected_interface.name() == actual_interface.name()
+					&& expected_interface.type_args() == actual_interface.type_args()
+				{
 					return true;
 				}
 
-				// For different interface names, use structural typing:
-				// Check if actual interface has all properties of expected interface
-				for (prop, expected_ty) in expected_interface.fields() {
-					match actual_interface.get_prop(prop) {
-						Some(actual_ty) => {
-							if !self.satisfies(*expected_ty, actual_ty) {
-								return false;
-							}
-						}
-						None => return false,
-					}
+				// an interface is assignable to the interfaces it extends
+				if actual_interface.ancestors().contains(&expected) {
+					return true;
 				}
 
-				// If expected interface has no properties, the structural check would pass
-				// but we should only allow this if the interfaces are related through inheritance
-				// For now, we'll be strict and require same names for empty interfaces
-				if expected_interface.fields().is_empty() && actual_interface.fields().is_empty() {
-					// Both are empty but different names - only allow if there's an inheritance relationship
-					// For simplicity, we'll return false to maintain existing behavior
-					false
-				} else {
-					true
+				if !self.satisfies_visibility(expected_interface.fields(), expected, actual) {
+					return false;
 				}
+
+				// For different interface names, use structural typing:
+				// Check if actual interface has all properties of expected interface
+				self.satisfies_members(
+					expected_interface.fields(),
+					expected_interface.optional(),
+					actual,
+				) && self.satisfies_index(expected_interface.index(), actual)
+					&& self.satisfies_signatures(expected_interface.signatures(), actual)
 			}
 
 			// Class inheritance checking
@@ -147,46 +289,194 @@ impl<'tcx> BaseChecker<'tcx> {
 			}
 
 			// Instance of class satisfies parent class
-			(Class(expected_class), Interface(actual_interface)) => {
+			(Class(expected_class), Interface(_)) => {
 				// An interface satisfies a class if it has all the properties of that class
-				for (prop, expected_ty) in expected_class.fields() {
-					match actual_interface.get_prop(prop) {
-						Some(actual_ty) => {
-							if !self.satisfies(*expected_ty, actual_ty) {
-								return false;
-							}
-						}
-						None => return false,
-					}
-				}
-				true
+				self.satisfies_members(expected_class.fields(), expected_class.optional(), actual)
 			}
 
 			// Object structural compatibility with excess property checking
 			(Object(expected_obj), Object(actual_obj)) => {
-				if expected_obj.fields.len() != actual_obj.fields.len() {
+				// excess optional members may never be present, and index signatures cover excess keys
+				if actual_obj.fields().keys().any(|key| {
+					!expected_obj.fields().contains_key(key)
+						&& !actual_obj.is_optional(key)
+						&& expected_obj.index().get_prop(key).is_none()
+				}) {
 					return false;
 				}
 
 				// For different interface names, use structural typing:
 				// Check if actual interface has all properties of expected interface
-				for (prop, expected_ty) in expected_obj.fields() {
-					match actual_obj.get_prop(prop) {
-						Some(actual_ty) => {
-							if !self.satisfies(*expected_ty, actual_ty) {
-								return false;
-							}
-						}
-						None => return false,
-					}
-				}
-
-				true
+				self.satisfies_members(expected_obj.fields(), expected_obj.optional(), actual)
+					&& self.satisfies_index(expected_obj.index(), actual)
+					&& self.satisfies_signatures(expected_obj.signatures(), actual)
+			}
+			// an enum member is its value elsewhere
+			(_, EnumMember(member)) if !matches!(expected.kind(), Enum(_) | EnumMember(_)) => {
+				self.satisfies(expected, member.value())
 			}
+			// keys are strings or numbers
+			(_, KeyOf(_)) if expected != actual => self.satisfies(
+				expected,
+				self.tcx.new_union(BTreeSet::from([
+					self.constants.string,
+					self.constants.number,
+				])),
+			),
+			// a type parameter satisfies whatever its constraint satisfies
+			(_, TypeParameter(param)) if expected != actual => match param.constraint {
+				Some(constraint) => self.satisfies(expected, constraint),
+				None => false,
+			},
 			_ => expected == actual,
+		}
+	}
+
+	/// Checks that `actual` has every member in `fields`; optional members may be
+	/// missing and are otherwise compared as `T | undefined`
+	fn satisfies_members(
+		&self,
+		fields: &BTreeMap<Atom, Ty<'tcx>>,
+		optional: &BTreeSet<Atom>,
+		actual: Ty<'tcx>,
+	) -> bool {
+		fields.iter().all(|(key, expected_ty)| {
+			let is_optional = optional.contains(key);
+
+			// an index signature alone can't provide a required member
+			match self.lookup_member(actual, key, is_optional) {
+				Some(actual_ty) => {
+					self.satisfies(self.optional_prop_ty(*expected_ty, is_optional), actual_ty)
+				}
+				None => is_optional,
+			}
+		})
+	}
+
+	/// Private and protected members only match the same members of the class declaring them,
+	/// so classes having them compare nominally
+	fn satisfies_visibility(
+		&self,
+		fields: &BTreeMap<Atom, Ty<'tcx>>,
+		expected: Ty<'tcx>,
+		actual: Ty<'tcx>,
+	) -> bool {
+		let visibility = |ty: Ty<'tcx>, key: &Atom| match ty.kind() {
+			TyKind::Interface(interface) => interface.visibility().get(key).cloned(),
+			_ => None,
+		};
+
+		fields
+			.keys()
+			.all(|key| visibility(expected, key) == visibility(actual, key))
+	}
+
+	/// Checks that `actual` can be called and constructed like each signature in `expected`
+	pub fn satisfies_signatures(&self, expected: &Signatures<'tcx>, actual: Ty<'tcx>) -> bool {
+		if expected.is_empty() {
+			return true;
+		}
+
+		let callable = match actual.kind() {
+			TyKind::Function(_) | TyKind::Overloaded(_) => Some(actual),
+			_ => self.object_signature(actual, false),
 		};
+		let constructable = match actual.kind() {
+			// an abstract class can't be constructed
+			TyKind::Class(class) if class.is_abstract() => None,
+			TyKind::Class(class) => Some(self.class_construct_signature(class)),
+			_ => self.object_signature(actual, true),
+		};
+
+		[
+			(&expected.call, callable),
+			(&expected.construct, constructable),
+		]
+		.into_iter()
+		.all(|(expected, actual)| {
+			expected
+				.iter()
+				.all(|expected| actual.is_some_and(|actual| self.satisfies(*expected, actual)))
+		})
+	}
+
+	/// A class as the signature `new (...) => Instance` of its constructor
+	fn class_construct_signature(&self, class: &Class<'tcx>) -> Ty<'tcx> {
+		let instance = self.tcx.new_interface(class.interface());
+		let signature = match class.ctor() {
+			Some(ctor) => Function::new(ctor.params.clone(), instance)
+				.with_required(ctor.required)
+				.with_rest(ctor.rest.clone()),
+			None => Function::new(vec![], instance),
+		};
+
+		self.tcx
+			.new_function(signature.with_type_params(class.type_params().to_vec()))
+	}
+
+	/// Checks that every member and index signature of `actual` is assignable to the index
+	/// signatures of `expected`; interfaces need an explicit index signature, unlike type literals
+	pub fn satisfies_index(&self, expected: &IndexSignatures<'tcx>, actual: Ty<'tcx>) -> bool {
+		if expected.is_empty() {
+			return true;
+		}
+
+		let (fields, optional, index, is_interface) = match actual.kind() {
+			TyKind::Interface(interface) => (
+				interface.fields(),
+				interface.optional(),
+				interface.index(),
+				true,
+			),
+			TyKind::Object(obj) => (obj.fields(), obj.optional(), obj.index(), false),
+			_ => return false,
+		};
+
+		if let Some(expected) = expected.string {
+			if is_interface && index.string.is_none() {
+				return false;
+			}
+
+			let index_ok = [index.string, index.number]
+				.into_iter()
+				.flatten()
+				.all(|actual| self.satisfies(expected, actual));
+			let fields_ok = fields.iter().all(|(key, actual)| {
+				self.satisfies(
+					expected,
+					self.optional_prop_ty(*actual, optional.contains(key)),
+				)
+			});
+
+			if !index_ok || !fields_ok {
+				return false;
+			}
+		}
+
+		if let Some(expected) = expected.number {
+			if is_interface && index.get_number().is_none() {
+				return false;
+			}
+
+			let index_ok = index
+				.get_number()
+				.is_none_or(|actual| self.satisfies(expected, actual));
+			let fields_ok = fields
+				.iter()
+				.filter(|(key, _)| IndexSignatures::is_numeric_key(key))
+				.all(|(key, actual)| {
+					self.satisfies(
+						expected,
+						self.optional_prop_ty(*actual, optional.contains(key)),
+					)
+				});
+
+			if !index_ok || !fields_ok {
+				return false;
+			}
+		}
 
-		result
+		true
 	}
 
 	pub fn overlaps(&self, left: Ty<'tcx>, right: Ty<'tcx>) -> bool {
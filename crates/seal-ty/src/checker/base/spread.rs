@@ -0,0 +1,155 @@
+use std::collections::BTreeSet;
+
+use swc_atoms::Atom;
+use swc_common::Spanned;
+use swc_ecma_ast::ExprOrSpread;
+
+use crate::{
+	Ty, TyKind,
+	checker::errors::ErrorKind,
+	kind::Function,
+	sir::{Local, LocalId, Value},
+};
+
+use super::BaseChecker;
+
+/// An element of an array literal or an argument, where `...tuple` is one per element
+#[derive(Debug, Clone, Copy)]
+pub enum Element<'tcx> {
+	Single(Local<'tcx>),
+	/// The element type of `...array`, which may be any number of elements
+	Variadic(Ty<'tcx>),
+}
+
+impl<'tcx> Element<'tcx> {
+	pub fn ty(&self) -> Ty<'tcx> {
+		match self {
+			Element::Single(local) => local.ty,
+			Element::Variadic(ty) => *ty,
+		}
+	}
+}
+
+/// The arguments of a call, where spread tuples are expanded into their elements
+#[derive(Debug, Clone, Default)]
+pub struct Args<'tcx> {
+	/// The arguments passed to the parameters by position
+	pub fixed: Vec<Local<'tcx>>,
+	/// The types of the arguments from the first spread array on, which only a rest parameter takes
+	pub variadic: Option<Vec<Ty<'tcx>>>,
+	/// The argument expressions
+	pub ids: Vec<LocalId>,
+}
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// Checks an element of an array literal or an argument, expanding it if it's spread
+	pub(super) fn check_spread(
+		&self,
+		elem: &ExprOrSpread,
+		expected_ty: Option<Ty<'tcx>>,
+	) -> (LocalId, Vec<Element<'tcx>>) {
+		let ExprOrSpread { expr, spread } = elem;
+		if spread.is_none() {
+			let value = self.check_expr(expr, expected_ty);
+			return (value.id, vec![Element::Single(value)]);
+		}
+
+		let value = self.check_expr(expr, None);
+		let elements = match value.ty.kind() {
+			TyKind::Tuple(tuple) => tuple
+				.elements
+				.iter()
+				.enumerate()
+				.map(|(index, ty)| {
+					Element::Single(
+						self.add_local(*ty, Value::Member(value.id, Atom::new(index.to_string()))),
+					)
+				})
+				.collect(),
+			TyKind::Array(array) => vec![Element::Variadic(array.element)],
+			TyKind::Err => vec![Element::Variadic(value.ty)],
+			_ => {
+				self.add_error_with_span(ErrorKind::NotArrayType(value.ty), expr.span());
+				vec![Element::Variadic(self.constants.err)]
+			}
+		};
+
+		(value.id, elements)
+	}
+
+	/// The union of the element types of an array, or `never` for none
+	pub(super) fn union_of_elements(&self, elements: &[Element<'tcx>]) -> Ty<'tcx> {
+		let tys: BTreeSet<_> = elements.iter().map(|elem| elem.ty()).collect();
+
+		match tys.len() {
+			1 => *tys.first().unwrap(),
+			_ => self.tcx.new_union(tys),
+		}
+	}
+
+	/// Checks the arguments of a call
+	pub(super) fn check_args(
+		&self,
+		args: &[ExprOrSpread],
+		expected_ty: Option<Ty<'tcx>>,
+	) -> Args<'tcx> {
+		let mut checked = Args::default();
+
+		for arg in args {
+			let (id, elements) = self.check_spread(arg, expected_ty);
+			checked.ids.push(id);
+
+			for element in elements {
+				match (element, &mut checked.variadic) {
+					(Element::Single(arg), None) => checked.fixed.push(arg),
+					(Element::Single(arg), Some(variadic)) => variadic.push(arg.ty),
+					(Element::Variadic(ty), variadic) => variadic.get_or_insert_default().push(ty),
+				}
+			}
+		}
+
+		checked
+	}
+
+	/// The arity error of a call, if any; a spread array can only be passed to a rest parameter
+	pub(super) fn check_args_arity(
+		&self,
+		function: &Function<'tcx>,
+		args: &Args<'tcx>,
+	) -> Option<ErrorKind<'tcx>> {
+		let Some(variadic) = &args.variadic else {
+			return self.check_arity(function, args.fixed.len());
+		};
+
+		let to_rest = args.fixed.len() >= function.params.len()
+			&& function
+				.rest
+				.as_ref()
+				.is_some_and(|(_, rest)| matches!(rest.kind(), TyKind::Array(_)));
+
+		(!to_rest && !variadic.contains(&self.constants.err)).then_some(ErrorKind::SpreadArgNotRest)
+	}
+
+	/// The errors of passing the arguments to a signature
+	pub(super) fn args_errors(
+		&self,
+		function: &Function<'tcx>,
+		args: &Args<'tcx>,
+	) -> Vec<ErrorKind<'tcx>> {
+		let arity = self.check_args_arity(function, args);
+		// a spread array which can't be passed to the rest parameter is not compared to other ones
+		let to_rest = arity.is_none();
+		let variadic = args.variadic.iter().flatten().copied().filter(|_| to_rest);
+		let arg_tys = args.fixed.iter().map(|arg| arg.ty).chain(variadic);
+
+		arity
+			.into_iter()
+			.chain(arg_tys.enumerate().filter_map(|(index, arg)| {
+				let (_, param) = function.param_at(index)?;
+
+				(!self.satisfies_param(function, index, arg))
+					.then(|| ErrorKind::NotAssignable(param, arg))
+			}))
+			.collect()
+	}
+}
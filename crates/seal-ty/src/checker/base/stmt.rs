@@ -1,16 +1,18 @@
 use std::collections::BTreeSet;
 
 use swc_ecma_ast::{
-	BreakStmt, ContinueStmt, DoWhileStmt, ExprStmt, ForStmt, IfStmt, Stmt, SwitchStmt, ThrowStmt,
-	TryStmt, WhileStmt,
+	BreakStmt, ContinueStmt, DoWhileStmt, Expr, ExprStmt, ForStmt, IfStmt, Stmt, SwitchStmt,
+	ThrowStmt, TryStmt, WhileStmt,
 };
 
-use crate::TyKind;
+use crate::{TyKind, symbol::Symbol};
 
 use super::BaseChecker;
 
-impl BaseChecker<'_> {
+impl<'tcx> BaseChecker<'tcx> {
 	pub fn check_stmt(&self, stmt: &Stmt) {
+		self.finish_overloads_before(stmt);
+
 		match stmt {
 			Stmt::Decl(decl) => self.check_decl(decl),
 			Stmt::Expr(ExprStmt { expr, .. }) => {
@@ -53,18 +55,18 @@ impl BaseChecker<'_> {
 
 						checker.set_ty(name, *narrowed_ty);
 
-						if let Some(next_checker) = scopes.get(i + 1) {
-							if let TyKind::Union(current) = current.ty.kind() {
-								let narrowed_arms = match narrowed_ty.kind() {
-									TyKind::Union(narrowed) => narrowed.arms(),
-									_ => &BTreeSet::from([*narrowed_ty]),
-								};
+						if let Some(next_checker) = scopes.get(i + 1)
+							&& let TyKind::Union(current) = current.ty.kind()
+						{
+							let narrowed_arms = match narrowed_ty.kind() {
+								TyKind::Union(narrowed) => narrowed.arms(),
+								_ => &BTreeSet::from([*narrowed_ty]),
+							};
 
-								let rest_arms =
-									current.arms().difference(narrowed_arms).copied().collect();
+							let rest_arms =
+								current.arms().difference(narrowed_arms).copied().collect();
 
-								next_checker.set_ty(name, self.tcx.new_union(rest_arms));
-							}
+							next_checker.set_ty(name, self.tcx.new_union(rest_arms));
 						}
 					}
 
@@ -76,11 +78,20 @@ impl BaseChecker<'_> {
 						checker.check_stmt(cons);
 					}
 				}
+
+				// NOTE: the other errors of the branches are dropped, as `===` doesn't narrow yet
+				for checker in scopes {
+					self.overloads
+						.borrow_mut()
+						.append(&mut checker.overloads.borrow_mut());
+					self.finish_overloads();
+				}
 			}
 			Stmt::Block(block) => {
 				for stmt in &block.stmts {
 					self.check_stmt(stmt);
 				}
+				self.finish_overloads();
 			}
 			Stmt::While(WhileStmt { test, body, .. }) => {
 				// Check test expression
@@ -89,11 +100,13 @@ impl BaseChecker<'_> {
 				// Check body in new scope
 				let checker = self.new_scoped_checker();
 				checker.check_stmt(body);
+				self.finish_scope(checker);
 			}
 			Stmt::DoWhile(DoWhileStmt { test, body, .. }) => {
 				// Check body first (since it always executes at least once)
 				let checker = self.new_scoped_checker();
 				checker.check_stmt(body);
+				self.finish_scope(checker);
 
 				// Then check test expression
 				self.check_expr(test, None);
@@ -132,6 +145,7 @@ impl BaseChecker<'_> {
 
 				// Check body
 				checker.check_stmt(body);
+				self.finish_scope(checker);
 			}
 			Stmt::Break(BreakStmt { .. }) => {
 				// Break statement - no type checking needed
@@ -145,21 +159,64 @@ impl BaseChecker<'_> {
 				..
 			}) => {
 				// Check discriminant expression
-				let _discriminant_value = self.check_expr(discriminant, None);
+				let discriminant_value = self.check_expr(discriminant, None);
+
+				// A variable of a union (or enum) type is narrowed to the arms matching the case,
+				// and to the arms no case matches in the default case
+				let narrowed = match discriminant.as_ref() {
+					Expr::Ident(ident) => self
+						.narrowing_arms(discriminant_value.ty)
+						.map(|arms| (Symbol::new(ident.to_id()), arms)),
+					_ => None,
+				};
+
+				let tests: Vec<_> = cases
+					.iter()
+					.map(|case| {
+						case.test
+							.as_ref()
+							.map(|test| self.check_expr(test, None).ty)
+					})
+					.collect();
+				let unhandled: BTreeSet<_> = narrowed
+					.iter()
+					.flat_map(|(_, arms)| arms)
+					.filter(|arm| !tests.contains(&Some(**arm)))
+					.copied()
+					.collect();
+
+				// arms of the previous cases falling through to the current one
+				let mut fallthrough = BTreeSet::new();
 
 				// Check each case
-				for case in cases {
+				for (case, test) in cases.iter().zip(tests) {
 					let checker = self.new_scoped_checker();
 
-					// Check case test expression if it exists
-					if let Some(test) = &case.test {
-						checker.check_expr(test, None);
+					if let Some((name, arms)) = &narrowed {
+						match test {
+							Some(test) => fallthrough.extend(
+								arms.iter()
+									.filter(|arm| self.overlaps(**arm, test))
+									.map(|arm| match self.satisfies(test, *arm) {
+										true => *arm,
+										false => test,
+									}),
+							),
+							None => fallthrough.extend(unhandled.iter().copied()),
+						}
+
+						checker.set_ty(name, self.tcx.new_union(fallthrough.clone()));
 					}
 
 					// Check case body
 					for stmt in &case.cons {
 						checker.check_stmt(stmt);
 					}
+					self.finish_scope(checker);
+
+					if !case.cons.is_empty() {
+						fallthrough.clear();
+					}
 				}
 			}
 			Stmt::Throw(ThrowStmt { arg, .. }) => {
@@ -177,6 +234,7 @@ impl BaseChecker<'_> {
 				for stmt in &block.stmts {
 					self.check_stmt(stmt);
 				}
+				self.finish_overloads();
 
 				// Check catch handler if it exists
 				if let Some(handler) = handler {
@@ -205,6 +263,7 @@ impl BaseChecker<'_> {
 					for stmt in &handler.body.stmts {
 						checker.check_stmt(stmt);
 					}
+					self.finish_scope(checker);
 				}
 
 				// Check finally block if it exists
@@ -212,9 +271,16 @@ impl BaseChecker<'_> {
 					for stmt in &finalizer.stmts {
 						self.check_stmt(stmt);
 					}
+					self.finish_overloads();
 				}
 			}
 			_ => todo!("{:#?}", stmt),
 		}
 	}
+
+	/// Ends a block checked by `checker`, whose overload signatures can't be implemented later
+	fn finish_scope(&self, checker: BaseChecker<'tcx>) {
+		checker.finish_overloads();
+		self.errors.borrow_mut().extend(checker.errors.into_inner());
+	}
 }
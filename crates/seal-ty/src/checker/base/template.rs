@@ -0,0 +1,214 @@
+use std::collections::BTreeMap;
+
+use swc_atoms::Atom;
+
+use crate::{
+	Ty, TyKind,
+	kind::{StringMapping, Template},
+	symbol::Symbol,
+};
+
+use super::BaseChecker;
+
+/// A string type spelled out character by character, with the holes of a template kept as types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'tcx> {
+	Char(char),
+	Hole(Ty<'tcx>),
+}
+
+impl<'tcx> BaseChecker<'tcx> {
+	/// `Uppercase<T>` and friends: applied to string literals, kept as a type otherwise
+	pub fn string_mapping(&self, mapping: StringMapping, ty: Ty<'tcx>) -> Ty<'tcx> {
+		match ty.kind() {
+			TyKind::String(Some(value)) => {
+				self.tcx.new_const_string(Atom::new(mapping.apply(value)))
+			}
+			TyKind::Union(uni) => self.tcx.new_union(
+				uni.arms()
+					.iter()
+					.map(|arm| self.string_mapping(mapping, *arm))
+					.collect(),
+			),
+			TyKind::String(None) | TyKind::Template(_) | TyKind::StringMapping(_, _) => {
+				self.tcx.new_string_mapping(mapping, ty)
+			}
+			_ if self.has_type_params(ty) => self.tcx.new_string_mapping(mapping, ty),
+			_ => ty,
+		}
+	}
+
+	/// Whether a string literal or another template matches the pattern of `expected`
+	pub fn satisfies_template(&self, expected: &Template<'tcx>, actual: Ty<'tcx>) -> bool {
+		let Some(tokens) = Self::tokens(actual) else {
+			return false;
+		};
+
+		Self::match_template(
+			&expected.quasis,
+			&expected.types,
+			&tokens,
+			&|hole, segment| self.hole_accepts(hole, segment),
+		)
+		.is_some()
+	}
+
+	/// Infers the type parameters in the holes of `param` from the matching parts of `arg`
+	pub(super) fn collect_template_candidates(
+		&self,
+		param: &Template<'tcx>,
+		arg: Ty<'tcx>,
+		candidates: &mut BTreeMap<Symbol, Vec<Ty<'tcx>>>,
+	) {
+		let Some(tokens) = Self::tokens(arg) else {
+			return;
+		};
+		let Some(segments) =
+			Self::match_template(&param.quasis, &param.types, &tokens, &|hole, segment| {
+				self.has_type_params(hole) || self.hole_accepts(hole, segment)
+			})
+		else {
+			return;
+		};
+
+		for (hole, segment) in param.types.iter().zip(segments) {
+			if self.has_type_params(*hole) {
+				self.collect_candidates(*hole, self.segment_ty(segment), candidates);
+			}
+		}
+	}
+
+	fn tokens(ty: Ty<'tcx>) -> Option<Vec<Token<'tcx>>> {
+		match ty.kind() {
+			TyKind::String(Some(value)) => Some(value.chars().map(Token::Char).collect()),
+			TyKind::Template(template) => {
+				let mut tokens: Vec<_> = template.quasis[0].chars().map(Token::Char).collect();
+				for (ty, quasi) in template.types.iter().zip(&template.quasis[1..]) {
+					tokens.push(Token::Hole(*ty));
+					tokens.extend(quasi.chars().map(Token::Char));
+				}
+				Some(tokens)
+			}
+			_ => None,
+		}
+	}
+
+	/// Splits `tokens` into the segments taken by the holes of a template, if its literal text matches.
+	/// Like TypeScript, each hole takes the shortest segment it accepts.
+	fn match_template<'a>(
+		quasis: &[Atom],
+		types: &[Ty<'tcx>],
+		tokens: &'a [Token<'tcx>],
+		accepts: &dyn Fn(Ty<'tcx>, &[Token<'tcx>]) -> bool,
+	) -> Option<Vec<&'a [Token<'tcx>]>> {
+		let mut rest = tokens;
+		for char in quasis[0].chars() {
+			match rest.split_first() {
+				Some((Token::Char(c), tail)) if *c == char => rest = tail,
+				_ => return None,
+			}
+		}
+
+		let Some((hole, types)) = types.split_first() else {
+			return rest.is_empty().then(Vec::new);
+		};
+
+		(0..=rest.len()).find_map(|end| {
+			if !accepts(*hole, &rest[..end]) {
+				return None;
+			}
+
+			let mut segments = Self::match_template(&quasis[1..], types, &rest[end..], accepts)?;
+			segments.insert(0, &rest[..end]);
+			Some(segments)
+		})
+	}
+
+	fn hole_accepts(&self, hole: Ty<'tcx>, segment: &[Token<'tcx>]) -> bool {
+		let text: Option<String> = segment
+			.iter()
+			.map(|token| match token {
+				Token::Char(c) => Some(*c),
+				Token::Hole(_) => None,
+			})
+			.collect();
+
+		match (hole.kind(), text) {
+			// any string fits, including other holes
+			(TyKind::String(None), _) => true,
+			(TyKind::Number(None), Some(text)) => Self::is_numeric_string(&text),
+			(TyKind::BigInt(None), Some(text)) => {
+				let digits = text.strip_prefix('-').unwrap_or(&text);
+				!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+			}
+			(TyKind::Boolean(None), Some(text)) => text == "true" || text == "false",
+			(TyKind::StringMapping(mapping, inner), Some(text)) => {
+				mapping.apply(&text) == text && self.hole_accepts(*inner, segment)
+			}
+			(_, Some(text)) => self.satisfies(hole, self.tcx.new_const_string(Atom::new(text))),
+			(_, None) => match segment {
+				[Token::Hole(ty)] => self.satisfies(hole, *ty),
+				_ => false,
+			},
+		}
+	}
+
+	/// Whether JavaScript reads `text` as a finite number, like `"1.5"`, `"1e3"` or `"0x10"`
+	fn is_numeric_string(text: &str) -> bool {
+		let is_digits = |digits: &str| digits.chars().all(|c| c.is_ascii_digit());
+
+		let radix = match text.get(..2) {
+			Some("0x" | "0X") => 16,
+			Some("0o" | "0O") => 8,
+			Some("0b" | "0B") => 2,
+			_ => 10,
+		};
+		if radix != 10 {
+			let digits = &text[2..];
+			return !digits.is_empty()
+				&& digits.chars().all(|c| c.is_digit(radix))
+				&& digits
+					.chars()
+					.fold(0.0, |value, c| {
+						value * radix as f64 + c.to_digit(radix).unwrap() as f64
+					})
+					.is_finite();
+		}
+
+		// a decimal literal, where `Infinity` isn't finite
+		let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+		let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+			Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+			None => (unsigned, None),
+		};
+		let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+		let exponent_is_valid = exponent.is_none_or(|exponent| {
+			let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+			!digits.is_empty() && is_digits(digits)
+		});
+
+		!(int.is_empty() && frac.is_empty())
+			&& is_digits(int)
+			&& is_digits(frac)
+			&& exponent_is_valid
+			&& text.parse::<f64>().is_ok_and(f64::is_finite)
+	}
+
+	/// The string type of a matched segment, e.g. to infer from
+	fn segment_ty(&self, segment: &[Token<'tcx>]) -> Ty<'tcx> {
+		let mut quasis = vec![String::new()];
+		let mut types = vec![];
+		for token in segment {
+			match token {
+				Token::Char(c) => quasis.last_mut().unwrap().push(*c),
+				Token::Hole(ty) => {
+					types.push(*ty);
+					quasis.push(String::new());
+				}
+			}
+		}
+
+		self.tcx
+			.new_template(quasis.into_iter().map(Atom::new).collect(), types)
+	}
+}
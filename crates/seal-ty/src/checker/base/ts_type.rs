@@ -1,11 +1,24 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use swc_atoms::Atom;
+use swc_common::Span;
 use swc_ecma_ast::{
-	TsEntityName, TsFnOrConstructorType, TsFnParam, TsKeywordTypeKind, TsLit, TsLitType, TsType,
-	TsTypeLit, TsTypeOperator, TsTypeRef, TsTupleType, TsUnionOrIntersectionType,
+	TsConditionalType, TsEntityName, TsFnOrConstructorType, TsFnParam, TsIndexSignature,
+	TsIndexedAccessType, TsInferType, TsKeywordTypeKind, TsLit, TsLitType, TsMappedType,
+	TsQualifiedName, TsThisType, TsType, TsTplLitType, TsTypeLit, TsTypeOperator, TsTypeParam,
+	TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsTupleType, TsUnionOrIntersectionType,
+	TruePlusMinus,
 };
+use swc_ecma_visit::{Visit, VisitWith};
 
-use crate::{Ty, TyKind, symbol::Symbol};
+use crate::{
+	Ty, TyKind,
+	kind::{
+		BigIntLit, Conditional, EnumMember, IndexSignatures, IndexedAccess, Mapped, MappedModifier,
+		StringMapping, TypeParameter,
+	},
+	symbol::Symbol,
+};
 
 use super::BaseChecker;
 
@@ -14,6 +27,7 @@ impl<'tcx> BaseChecker<'tcx> {
 		match tstype {
 			TsType::TsKeywordType(keyword) => match keyword.kind {
 				TsKeywordTypeKind::TsNumberKeyword => self.constants.number,
+				TsKeywordTypeKind::TsBigIntKeyword => self.constants.bigint,
 				TsKeywordTypeKind::TsStringKeyword => self.constants.string,
 				TsKeywordTypeKind::TsBooleanKeyword => self.constants.boolean,
 				TsKeywordTypeKind::TsVoidKeyword => self.constants.void,
@@ -24,25 +38,11 @@ impl<'tcx> BaseChecker<'tcx> {
 				_ => todo!("{:#?}", keyword),
 			},
 			TsType::TsFnOrConstructorType(fn_or_constructor) => match fn_or_constructor {
-				TsFnOrConstructorType::TsFnType(fn_) => {
-					let ret = self.build_ts_type(&fn_.type_ann.type_ann);
-
-					let mut params = vec![];
-					for param in &fn_.params {
-						match param {
-							TsFnParam::Ident(ident) => {
-								let name = Symbol::new(ident.to_id());
-								let ty =
-									self.build_ts_type(&ident.type_ann.as_ref().unwrap().type_ann);
-
-								params.push((name, ty));
-							}
-							_ => todo!("{:#?}", param),
-						};
-					}
-
-					self.tcx.new_function(crate::kind::Function { params, ret })
-				}
+				TsFnOrConstructorType::TsFnType(fn_) => self.build_signature(
+					fn_.type_params.as_deref(),
+					&fn_.params,
+					Some(&fn_.type_ann),
+				),
 				_ => todo!("{:#?}", fn_or_constructor),
 			},
 			TsType::TsUnionOrIntersectionType(ty) => match ty {
@@ -52,50 +52,92 @@ impl<'tcx> BaseChecker<'tcx> {
 						.map(|ty| self.build_ts_type(ty))
 						.collect::<BTreeSet<_>>(),
 				),
-				TsUnionOrIntersectionType::TsIntersectionType(_) => unimplemented!(),
+				TsUnionOrIntersectionType::TsIntersectionType(ty) => self.tcx.new_intersection(
+					ty.types
+						.iter()
+						.map(|ty| self.build_ts_type(ty))
+						.collect::<BTreeSet<_>>(),
+				),
 			},
 			TsType::TsLitType(TsLitType { lit, .. }) => match lit {
 				TsLit::Str(str) => self.tcx.new_const_string(str.value.clone()),
-				TsLit::Number(num) => {
-					if num.value.fract() != 0.0 {
-						self.add_error_with_span(
-							crate::checker::errors::ErrorKind::InvalidNumberLiteral(num.value),
-							num.span,
-						);
-						self.constants.number
-					} else {
-						self.tcx.new_const_number(num.value as i64)
-					}
-				}
+				TsLit::Number(num) => self.tcx.new_const_number(num.value),
+				TsLit::BigInt(bigint) => self
+					.tcx
+					.new_const_bigint(BigIntLit::new(&bigint.value.to_string())),
 				TsLit::Bool(bool) => self.tcx.new_const_boolean(bool.value),
-				_ => todo!("{:#?}", lit),
+				TsLit::Tpl(TsTplLitType { types, quasis, .. }) => self.tcx.new_template(
+					quasis
+						.iter()
+						.map(|quasi| quasi.cooked.clone().unwrap_or_else(|| quasi.raw.clone()))
+						.collect(),
+					types.iter().map(|ty| self.build_ts_type(ty)).collect(),
+				),
 			},
-			TsType::TsTypeLit(TsTypeLit { members, .. }) => {
-				let mut fields = BTreeMap::new();
-				for member in members {
-					match member {
-						swc_ecma_ast::TsTypeElement::TsPropertySignature(prop) => {
-							let name = prop.key.as_ident().unwrap().sym.clone();
-							let ty = self.build_ts_type(&prop.type_ann.as_ref().unwrap().type_ann);
-							fields.insert(name, ty);
-						}
-						_ => todo!("{:#?}", member),
+			TsType::TsTypeLit(TsTypeLit { members, span }) => {
+				self.tcx.new_object(self.build_type_members(members, *span))
+			}
+			TsType::TsThisType(TsThisType { span }) => {
+				match self.get_binding(&Symbol::new_this()) {
+					Some(binding) => binding.ty,
+					None => {
+						self.add_error_with_span(
+							crate::checker::errors::ErrorKind::ThisTypeOutsideClass,
+							*span,
+						);
+						self.constants.err
 					}
 				}
-
-				self.tcx.new_object(crate::kind::Object { fields })
 			}
 			TsType::TsTypeRef(TsTypeRef {
-				type_name, span, ..
+				type_name,
+				type_params,
+				span,
+				..
 			}) => {
 				let name = Symbol::new(match type_name {
 					TsEntityName::Ident(ident) => ident.to_id(),
-					TsEntityName::TsQualifiedName(_) => unimplemented!(),
+					TsEntityName::TsQualifiedName(qualified) => {
+						return self.qualified_type(qualified, *span);
+					}
 				});
 
+				let type_args: Vec<_> = type_params
+					.iter()
+					.flat_map(|type_params| &type_params.params)
+					.map(|ty| self.build_ts_type(ty))
+					.collect();
+
 				if let Some(binding) = self.get_binding(&name) {
 					match binding.ty.kind() {
-						TyKind::Class(class) => self.tcx.new_interface(class.interface()),
+						// the name of a class is its instance type, unlike an alias of `typeof C`
+						TyKind::Class(class) if *class.name() == name => {
+							let instance = self.tcx.new_interface(class.interface());
+							self.apply_type_args(
+								instance,
+								class.type_params(),
+								instance,
+								&type_args,
+								*span,
+							)
+						}
+						TyKind::EnumObject(enum_) if type_args.is_empty() => {
+							self.tcx.new_enum(enum_.clone())
+						}
+						TyKind::Generic(generic) => self.apply_type_args(
+							binding.ty,
+							&generic.type_params,
+							generic.ty,
+							&type_args,
+							*span,
+						),
+						_ if !type_args.is_empty() => {
+							self.add_error_with_span(
+								crate::checker::errors::ErrorKind::NotGeneric(binding.ty),
+								*span,
+							);
+							self.constants.err
+						}
 						_ => binding.ty,
 					}
 				} else {
@@ -103,6 +145,22 @@ impl<'tcx> BaseChecker<'tcx> {
 					match name.name().as_ref() {
 						"Object" => self.constants.object,
 						"RegExp" => self.constants.regexp,
+						"Uppercase" | "Lowercase" | "Capitalize" | "Uncapitalize" => {
+							let mapping = StringMapping::from_name(name.name()).unwrap();
+							match type_args.as_slice() {
+								[ty] => self.string_mapping(mapping, *ty),
+								_ => {
+									self.add_error_with_span(
+										crate::checker::errors::ErrorKind::WrongNumTypeArgs(
+											1,
+											type_args.len(),
+										),
+										*span,
+									);
+									self.constants.err
+								}
+							}
+						}
 						_ => {
 							self.add_error_with_span(
 								crate::checker::errors::ErrorKind::CannotFindName(name),
@@ -125,17 +183,489 @@ impl<'tcx> BaseChecker<'tcx> {
 					.collect();
 				self.tcx.new_tuple(elements)
 			}
-			TsType::TsTypeOperator(TsTypeOperator { op, type_ann, .. }) => {
-				match op.as_str() {
-					"readonly" => {
-						// For readonly, we just return the inner type
-						// In a more complete implementation, we'd track readonly-ness
-						self.build_ts_type(type_ann)
+			TsType::TsTypeOperator(TsTypeOperator { op, type_ann, span }) => match op.as_str() {
+				"readonly" => match self.build_ts_type(type_ann).kind() {
+					TyKind::Array(array) => self.tcx.new_readonly_array(array.element),
+					TyKind::Tuple(tuple) => self.tcx.new_readonly_tuple(tuple.elements.clone()),
+					_ => {
+						self.add_error_with_span(
+							crate::checker::errors::ErrorKind::ReadonlyModifierNotPermitted,
+							*span,
+						);
+						self.constants.err
+					}
+				},
+				"keyof" => {
+					let ty = self.build_ts_type(type_ann);
+					self.key_of(ty)
+				}
+				_ => todo!("Type operator: {}", op),
+			},
+			TsType::TsTypeQuery(TsTypeQuery {
+				expr_name, span, ..
+			}) => match expr_name {
+				TsTypeQueryExpr::TsEntityName(entity) => self.type_query(entity, *span),
+				TsTypeQueryExpr::Import(_) => todo!("{:#?}", expr_name),
+			},
+			TsType::TsIndexedAccessType(TsIndexedAccessType {
+				obj_type,
+				index_type,
+				span,
+				..
+			}) => {
+				let object = self.build_ts_type(obj_type);
+				let index = self.build_ts_type(index_type);
+
+				self.indexed_access(object, index).unwrap_or_else(|error| {
+					self.add_error_with_span(error, *span);
+					self.constants.err
+				})
+			}
+			TsType::TsConditionalType(TsConditionalType {
+				check_type,
+				extends_type,
+				true_type,
+				false_type,
+				..
+			}) => {
+				let check = self.build_ts_type(check_type);
+
+				// `infer` placeholders are bound before building `extends`, so the branches can refer to them
+				let mut collector = InferCollector::default();
+				extends_type.visit_with(&mut collector);
+				let infer = collector
+					.params
+					.iter()
+					.map(|param| {
+						let name = Symbol::new(param.name.to_id());
+						let constraint = param
+							.constraint
+							.as_ref()
+							.map(|constraint| self.build_ts_type(constraint));
+						let ty = self.tcx.new_type_parameter(TypeParameter::new(
+							name.clone(),
+							constraint,
+							None,
+						));
+						self.set_binding(&name, None, ty, false);
+						ty
+					})
+					.collect();
+
+				let extends = self.build_ts_type(extends_type);
+				let true_ty = self.build_ts_type(true_type);
+				let false_ty = self.build_ts_type(false_type);
+
+				self.conditional(Conditional {
+					check,
+					extends,
+					true_ty,
+					false_ty,
+					infer,
+				})
+			}
+			TsType::TsMappedType(TsMappedType {
+				readonly,
+				type_param,
+				name_type,
+				optional,
+				type_ann,
+				..
+			}) => {
+				let name = Symbol::new(type_param.name.to_id());
+				let constraint = type_param
+					.constraint
+					.as_ref()
+					.expect("mapped type parameters always have a constraint");
+				// `[K in keyof T]` keeps the modifiers of the properties of `T`
+				let source = match constraint.as_ref() {
+					TsType::TsTypeOperator(TsTypeOperator { op, type_ann, .. })
+						if op.as_str() == "keyof" =>
+					{
+						Some(self.build_ts_type(type_ann))
+					}
+					_ => None,
+				};
+				let constraint = match source {
+					Some(source) => self.key_of(source),
+					None => self.build_ts_type(constraint),
+				};
+
+				let param = self.tcx.new_type_parameter(TypeParameter::new(
+					name.clone(),
+					Some(constraint),
+					None,
+				));
+				self.set_binding(&name, None, param, false);
+
+				let modifier = |modifier: &Option<TruePlusMinus>| {
+					modifier.map(|modifier| match modifier {
+						TruePlusMinus::True | TruePlusMinus::Plus => MappedModifier::Add,
+						TruePlusMinus::Minus => MappedModifier::Remove,
+					})
+				};
+
+				self.mapped(Mapped {
+					param,
+					constraint,
+					source,
+					name: name_type.as_ref().map(|name| self.build_ts_type(name)),
+					ty: match type_ann {
+						Some(type_ann) => self.build_ts_type(type_ann),
+						None => self.constants.unknown,
+					},
+					optional: modifier(optional),
+					readonly: modifier(readonly),
+				})
+			}
+			// `infer` type parameters are bound before building the `extends` clause holding them
+			TsType::TsInferType(TsInferType { type_param, span }) => {
+				match self.get_binding(&Symbol::new(type_param.name.to_id())) {
+					Some(binding) => binding.ty,
+					None => {
+						self.add_error_with_span(
+							crate::checker::errors::ErrorKind::InferOutsideConditional,
+							*span,
+						);
+						self.constants.err
 					}
-					_ => todo!("Type operator: {}", op),
 				}
 			}
 			_ => todo!("{:#?}", tstype),
 		}
 	}
+
+	/// A type named through a namespace, i.e. an enum member type like `Color.Red`
+	fn qualified_type(&self, qualified: &TsQualifiedName, span: Span) -> Ty<'tcx> {
+		let namespace = match &qualified.left {
+			TsEntityName::Ident(namespace) => namespace,
+			// an enum member like `Color.Red` isn't a namespace, and there are no other namespaces
+			TsEntityName::TsQualifiedName(nested) => {
+				let mut root = &nested.left;
+				while let TsEntityName::TsQualifiedName(nested) = root {
+					root = &nested.left;
+				}
+				let TsEntityName::Ident(root) = root else {
+					unreachable!()
+				};
+
+				let name = Symbol::new(root.to_id());
+				let (error, span) = match self.get_binding(&name) {
+					Some(_) => {
+						let namespace = Atom::new(Self::entity_text(&qualified.left));
+						(
+							crate::checker::errors::ErrorKind::NoExportedMember(
+								Symbol::new((namespace, root.ctxt)),
+								qualified.right.sym.clone(),
+							),
+							nested.span,
+						)
+					}
+					None => (
+						crate::checker::errors::ErrorKind::CannotFindName(name),
+						root.span,
+					),
+				};
+				self.add_error_with_span(error, span);
+				return self.constants.err;
+			}
+		};
+		let name = Symbol::new(namespace.to_id());
+		let key = qualified.right.sym.clone();
+
+		match self.get_binding(&name).map(|binding| binding.ty.kind()) {
+			Some(TyKind::EnumObject(enum_)) if enum_.get_member(&key).is_some() => self
+				.tcx
+				.new_enum_member(EnumMember::new(enum_.clone(), key)),
+			Some(TyKind::EnumObject(_)) => {
+				self.add_error_with_span(
+					crate::checker::errors::ErrorKind::NoExportedMember(name, key),
+					span,
+				);
+				self.constants.err
+			}
+			_ => {
+				self.add_error_with_span(
+					crate::checker::errors::ErrorKind::CannotFindName(name),
+					span,
+				);
+				self.constants.err
+			}
+		}
+	}
+
+	/// The source text of a name like `A.B.C`
+	fn entity_text(entity: &TsEntityName) -> String {
+		match entity {
+			TsEntityName::Ident(ident) => ident.sym.to_string(),
+			TsEntityName::TsQualifiedName(qualified) => {
+				format!(
+					"{}.{}",
+					Self::entity_text(&qualified.left),
+					qualified.right.sym
+				)
+			}
+		}
+	}
+
+	/// Type of a value referenced in a type, e.g. `typeof config.port`
+	fn type_query(&self, entity: &TsEntityName, span: Span) -> Ty<'tcx> {
+		match entity {
+			TsEntityName::Ident(ident) => {
+				let name = Symbol::new(ident.to_id());
+				match self.get_binding(&name) {
+					Some(binding) => binding.ty,
+					None => {
+						self.add_error_with_span(
+							crate::checker::errors::ErrorKind::CannotFindName(name),
+							span,
+						);
+						self.constants.err
+					}
+				}
+			}
+			TsEntityName::TsQualifiedName(qualified) => {
+				let ty = self.type_query(&qualified.left, span);
+				if let TyKind::Err = ty.kind() {
+					return ty;
+				}
+
+				let key = qualified.right.sym.clone();
+				self.lookup_property(ty, &key).unwrap_or_else(|| {
+					self.add_error_with_span(
+						crate::checker::errors::ErrorKind::PropertyDoesNotExist(ty, key),
+						span,
+					);
+					self.constants.err
+				})
+			}
+		}
+	}
+
+	/// Type parameters (and types derived from them) can only be resolved on instantiation
+	pub fn is_deferred(&self, ty: Ty<'tcx>) -> bool {
+		matches!(
+			ty.kind(),
+			TyKind::TypeParameter(_) | TyKind::KeyOf(_) | TyKind::IndexedAccess(_)
+		)
+	}
+
+	/// `keyof ty`: the union of the literal keys of `ty`, plus `string` or `number` for its index signatures
+	pub fn key_of(&self, ty: Ty<'tcx>) -> Ty<'tcx> {
+		if self.is_deferred(ty) {
+			return self.tcx.new_key_of(ty);
+		}
+
+		let empty = BTreeMap::new();
+		let (fields, index, visibility) = match ty.kind() {
+			TyKind::Object(obj) => (obj.fields(), obj.index(), &empty),
+			// private and protected members of a class aren't keys
+			TyKind::Interface(interface) => (
+				interface.fields(),
+				interface.index(),
+				interface.visibility(),
+			),
+			TyKind::Array(_) | TyKind::Tuple(_) => return self.constants.number,
+			// e.g. 'keyof typeof Color'
+			TyKind::EnumObject(enum_) => {
+				return self.tcx.new_union(
+					enum_
+						.members()
+						.iter()
+						.map(|(key, _)| self.tcx.new_const_string(key.clone()))
+						.collect(),
+				);
+			}
+			// only the keys common to every arm
+			TyKind::Union(uni) => {
+				return self
+					.tcx
+					.new_intersection(uni.arms().iter().map(|arm| self.key_of(*arm)).collect());
+			}
+			TyKind::Intersection(intersection) => {
+				return self.tcx.new_union(
+					intersection
+						.parts()
+						.iter()
+						.map(|part| self.key_of(*part))
+						.collect(),
+				);
+			}
+			_ => return self.constants.never,
+		};
+
+		let mut keys: BTreeSet<_> = fields
+			.keys()
+			.filter(|key| !visibility.contains_key(*key))
+			.map(|key| self.tcx.new_const_string(key.clone()))
+			.collect();
+		// a string index signature accepts numeric keys too
+		if index.string.is_some() {
+			keys.extend([self.constants.string, self.constants.number]);
+		} else if index.number.is_some() {
+			keys.insert(self.constants.number);
+		}
+
+		self.tcx.new_union(keys)
+	}
+
+	/// `object[index]` in a type: the property, element or index signature type selected by `index`
+	pub fn indexed_access(
+		&self,
+		object: Ty<'tcx>,
+		index: Ty<'tcx>,
+	) -> Result<Ty<'tcx>, crate::checker::errors::ErrorKind<'tcx>> {
+		use crate::checker::errors::ErrorKind;
+
+		if self.is_deferred(object) || self.is_deferred(index) {
+			return Ok(self
+				.tcx
+				.new_indexed_access(IndexedAccess::new(object, index)));
+		}
+
+		match (object.kind(), index.kind()) {
+			(TyKind::Err, _) | (_, TyKind::Err) => Ok(self.constants.err),
+			(_, TyKind::Never) => Ok(self.constants.never),
+			// distributes over unions on either side
+			(_, TyKind::Union(uni)) => Ok(self.tcx.new_union(
+				uni.arms()
+					.iter()
+					.map(|arm| self.indexed_access(object, *arm))
+					.collect::<Result<_, _>>()?,
+			)),
+			(TyKind::Union(uni), _) => Ok(self.tcx.new_union(
+				uni.arms()
+					.iter()
+					.map(|arm| self.indexed_access(*arm, index))
+					.collect::<Result<_, _>>()?,
+			)),
+			(TyKind::Array(array), TyKind::Number(_)) => Ok(array.element),
+			(TyKind::Tuple(tuple), TyKind::Number(None)) => {
+				Ok(self.tcx.new_union(tuple.elements.iter().copied().collect()))
+			}
+			(TyKind::Tuple(tuple), TyKind::Number(Some(value))) if value.as_index().is_some() => {
+				tuple
+					.elements
+					.get(value.as_index().unwrap())
+					.copied()
+					.ok_or(ErrorKind::TupleIndexOutOfBounds(
+						object,
+						tuple.elements.len(),
+						*value,
+					))
+			}
+			(_, TyKind::String(Some(_)) | TyKind::Number(Some(_))) => {
+				let key = match index.kind() {
+					TyKind::String(Some(key)) => key.clone(),
+					TyKind::Number(Some(value)) => Atom::new(value.to_string()),
+					_ => unreachable!(),
+				};
+
+				self.lookup_property(object, &key)
+					.ok_or(ErrorKind::PropertyDoesNotExist(object, key))
+			}
+			(_, TyKind::String(None) | TyKind::Number(None)) => {
+				let index_signatures = match object.kind() {
+					TyKind::Object(obj) => *obj.index(),
+					TyKind::Interface(interface) => *interface.index(),
+					_ => IndexSignatures::default(),
+				};
+
+				match index.kind() {
+					TyKind::Number(_) => index_signatures.get_number(),
+					_ => index_signatures.string,
+				}
+				.ok_or(ErrorKind::NoMatchingIndexSignature(object, index))
+			}
+			_ => Err(ErrorKind::TypeCannotBeUsedAsIndexType(index)),
+		}
+	}
+
+	/// Adds `[key: string]: T` or `[key: number]: T` to `index`
+	pub fn build_index_signature(&self, sig: &TsIndexSignature, index: &mut IndexSignatures<'tcx>) {
+		let value = match &sig.type_ann {
+			Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
+			None => self.constants.unknown,
+		};
+		let key = match sig.params.as_slice() {
+			[TsFnParam::Ident(ident)] => ident
+				.type_ann
+				.as_ref()
+				.map(|type_ann| self.build_ts_type(&type_ann.type_ann)),
+			_ => None,
+		};
+
+		match key.map(|key| key.kind()) {
+			Some(TyKind::String(None)) => index.string = Some(value),
+			Some(TyKind::Number(None)) => index.number = Some(value),
+			_ => self.add_error_with_span(
+				crate::checker::errors::ErrorKind::InvalidIndexSignatureParam,
+				sig.span,
+			),
+		}
+	}
+
+	/// Every property must be assignable to the index signature covering its name,
+	/// and a number index signature must be assignable to the string one
+	pub fn check_index_signatures(
+		&self,
+		fields: &BTreeMap<Atom, Ty<'tcx>>,
+		optional: &BTreeSet<Atom>,
+		index: &IndexSignatures<'tcx>,
+		span: Span,
+	) {
+		for (key, ty) in fields {
+			let ty = self.optional_prop_ty(*ty, optional.contains(key));
+
+			if let Some(value) = index.get_prop(key)
+				&& !self.satisfies(value, ty)
+			{
+				let key_ty = match (IndexSignatures::is_numeric_key(key), index.number) {
+					(true, Some(_)) => self.constants.number,
+					_ => self.constants.string,
+				};
+
+				self.add_error_with_span(
+					crate::checker::errors::ErrorKind::PropertyNotAssignableToIndex(
+						key.clone(),
+						ty,
+						key_ty,
+						value,
+					),
+					span,
+				);
+			}
+		}
+
+		if let (Some(string), Some(number)) = (index.string, index.number)
+			&& !self.satisfies(string, number)
+		{
+			self.add_error_with_span(
+				crate::checker::errors::ErrorKind::PropertyNotAssignableToIndex(
+					Atom::new("number"),
+					number,
+					self.constants.string,
+					string,
+				),
+				span,
+			);
+		}
+	}
+}
+
+/// Collects the type parameters declared with `infer` in the `extends` clause of a conditional type
+#[derive(Default)]
+struct InferCollector {
+	params: Vec<TsTypeParam>,
+}
+
+impl Visit for InferCollector {
+	fn visit_ts_infer_type(&mut self, infer: &TsInferType) {
+		self.params.push(infer.type_param.clone());
+	}
+
+	// nested conditional types bind their own placeholders
+	fn visit_ts_conditional_type(&mut self, conditional: &TsConditionalType) {
+		conditional.check_type.visit_with(self);
+	}
 }
@@ -7,9 +7,11 @@ impl<'tcx> BaseChecker<'tcx> {
 		use TyKind::*;
 
 		match ty.kind() {
-			String(_) => self.constants.string,
+			String(_) | Template(_) | StringMapping(_, _) => self.constants.string,
 			Number(_) => self.constants.number,
+			BigInt(_) => self.constants.bigint,
 			Boolean(_) => self.constants.boolean,
+			EnumMember(member) => self.tcx.new_enum(member.parent.clone()),
 			Guard(_, _) => self.constants.boolean,
 			_ => ty,
 		}
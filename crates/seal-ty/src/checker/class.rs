@@ -1,28 +1,129 @@
 use std::collections::HashMap;
 use std::rc::Rc;
-use std::{collections::BTreeMap, ops::Deref};
-
-use swc_common::Spanned;
-use swc_ecma_ast::{Class, ClassMember, Constructor, ParamOrTsParamProp, Pat, PropName, Stmt};
-
-use super::base::BaseChecker;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	ops::Deref,
+};
+
+use swc_atoms::Atom;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{
+	Accessibility, ArrowExpr, AssignPat, BlockStmt, Class, ClassMember, ClassMethod, ClassProp,
+	Constructor, Expr, Function, Ident, MemberExpr, MemberProp, MethodKind, ParamOrTsParamProp,
+	Pat, PrivateMethod, PrivateProp, PropName, StaticBlock, Stmt, TsExprWithTypeArgs, TsParamProp,
+	TsParamPropParam, TsTypeAnn,
+};
+use swc_ecma_visit::{Visit, VisitWith};
+
+use super::base::{BaseChecker, ClassScope, Params};
 use super::errors::{Error, ErrorKind};
 use super::function::FunctionChecker;
 
+use crate::kind::{IndexSignatures, Interface, TypeParameter, Visibility};
 use crate::sir::{self, Value};
 use crate::symbol::Symbol;
 
 pub struct ClassCheckerResult<'tcx> {
-	pub ty: crate::kind::Class<'tcx>,
+	pub ty: crate::Ty<'tcx>,
 	pub def: sir::Class,
 	pub errors: Vec<Error<'tcx>>,
 }
 
+/// The members of one side of a class, the instance or the class itself
+#[derive(Default)]
+struct Members<'tcx> {
+	fields: BTreeMap<Atom, crate::Ty<'tcx>>,
+	optional: BTreeSet<Atom>,
+	readonly: BTreeSet<Atom>,
+	visibility: BTreeMap<Atom, Visibility>,
+	/// the abstract members which are not implemented yet, with the class declaring them
+	abstract_members: BTreeMap<Atom, Symbol>,
+	/// the accessors declared by the class; a getter without a setter is readonly
+	getters: BTreeSet<Atom>,
+	setters: BTreeSet<Atom>,
+	index: IndexSignatures<'tcx>,
+}
+
+impl<'tcx> Members<'tcx> {
+	fn inherit(&mut self, parent: &Interface<'tcx>) {
+		self.fields.extend(parent.fields().clone());
+		self.optional.extend(parent.optional().iter().cloned());
+		self.readonly.extend(parent.readonly().iter().cloned());
+		self.visibility.extend(parent.visibility().clone());
+		self.index = *parent.index();
+	}
+
+	fn insert_prop(
+		&mut self,
+		key: Atom,
+		ty: crate::Ty<'tcx>,
+		is_optional: bool,
+		is_readonly: bool,
+	) {
+		if is_optional {
+			self.optional.insert(key.clone());
+		}
+		if is_readonly {
+			self.readonly.insert(key.clone());
+		} else {
+			self.readonly.remove(&key);
+		}
+		self.fields.insert(key, ty);
+	}
+
+	fn insert_method(&mut self, key: Atom, kind: MethodKind, ty: crate::Ty<'tcx>) {
+		match kind {
+			MethodKind::Method => {
+				self.readonly.remove(&key);
+			}
+			MethodKind::Getter => {
+				if !self.setters.contains(&key) {
+					self.readonly.insert(key.clone());
+				}
+				self.getters.insert(key.clone());
+			}
+			MethodKind::Setter => {
+				self.readonly.remove(&key);
+				self.setters.insert(key.clone());
+				// the getter of the property gives its type
+				if self.getters.contains(&key) {
+					return;
+				}
+			}
+		}
+		self.fields.insert(key, ty);
+	}
+
+	fn set_visibility(&mut self, key: Atom, visibility: Option<Visibility>) {
+		match visibility {
+			Some(visibility) => self.visibility.insert(key, visibility),
+			None => self.visibility.remove(&key),
+		};
+	}
+
+	fn set_abstract(&mut self, key: Atom, class: Option<Symbol>) {
+		match class {
+			Some(class) => self.abstract_members.insert(key, class),
+			None => self.abstract_members.remove(&key),
+		};
+	}
+
+	fn to_interface(&self, name: &Symbol, type_params: Vec<crate::Ty<'tcx>>) -> Interface<'tcx> {
+		Interface::new_with_type_args(name.clone(), type_params, self.fields.clone())
+			.with_optional(self.optional.clone())
+			.with_readonly(self.readonly.clone())
+			.with_visibility(self.visibility.clone())
+			.with_index(self.index)
+	}
+}
+
 #[derive(Debug)]
 pub struct ClassChecker<'tcx> {
 	// TODO: replace with more basic checker (only with tcx, errors, and constants)
 	base: BaseChecker<'tcx>,
 	name: Symbol,
+	/// The span of the class name
+	span: Span,
 }
 
 impl<'tcx> Deref for ClassChecker<'tcx> {
@@ -34,16 +135,23 @@ impl<'tcx> Deref for ClassChecker<'tcx> {
 }
 
 impl<'tcx> ClassChecker<'tcx> {
-	pub fn new_with_parent(parent: &BaseChecker<'tcx>, name: &Symbol) -> ClassChecker<'tcx> {
+	pub fn new_with_parent(
+		parent: &BaseChecker<'tcx>,
+		name: &Symbol,
+		span: Span,
+	) -> ClassChecker<'tcx> {
 		let base = parent.new_scoped_checker();
 
 		ClassChecker {
 			base,
 			name: name.clone(),
+			span,
 		}
 	}
 
 	pub fn check_class(self, class: &Class) -> ClassCheckerResult<'tcx> {
+		let type_params = self.bind_type_params(class.type_params.as_deref());
+
 		// Check for parent class
 		let parent: Option<crate::Ty<'tcx>> = if let Some(super_class) = &class.super_class {
 			// Evaluate the super class expression
@@ -63,108 +171,331 @@ impl<'tcx> ClassChecker<'tcx> {
 			None
 		};
 
-		let mut ctor = None;
-		let mut field_tys = BTreeMap::new();
+		// the members of the class and its parents are accessible in its body
+		let mut classes = vec![self.name.clone()];
+		let mut ancestor = parent;
+		while let Some(parent_ty) = ancestor
+			&& let crate::TyKind::Class(parent_class) = parent_ty.kind()
+		{
+			classes.push(parent_class.interface().name().clone());
+			ancestor = parent_class.parent();
+		}
+		self.set_class_scope(Some(ClassScope {
+			classes,
+			is_constructor: false,
+		}));
+		// `this` in the signatures of the members is whatever type the object has
+		self.bind_this(self.tcx.new_type_parameter(TypeParameter::new(
+			Symbol::new_this(),
+			None,
+			None,
+		)));
+
+		self.check_init_order(class);
+
+		let mut instance_members = Members::default();
+		let mut static_members = Members::default();
 		let mut methods = vec![];
+		// the signatures of the methods, whose bodies are checked once the instance type is known
+		let mut signatures = vec![];
+		// the fields typed by their initializers, which are inferred once the other members are known
+		let mut unannotated = vec![];
+		// the parameters of the constructors, which declare the parameter properties
+		let mut ctor_params = vec![];
+		// the spans of the members declared by the class
+		let mut spans = BTreeMap::new();
 
 		// If there's a parent class, inherit its fields
-		if let Some(parent_ty) = parent {
-			if let crate::TyKind::Class(parent_class) = parent_ty.kind() {
-				// Copy parent fields to child
-				for (key, ty) in parent_class.fields() {
-					field_tys.insert(key.clone(), *ty);
+		if let Some(parent_ty) = parent
+			&& let crate::TyKind::Class(parent_class) = parent_ty.kind()
+		{
+			// Instantiate a generic parent with the type arguments given to 'extends'
+			let parent_instance = self.tcx.new_interface(parent_class.interface());
+			let parent_instance = match &class.super_type_params {
+				Some(super_type_params) => {
+					let type_args: Vec<_> = super_type_params
+						.params
+						.iter()
+						.map(|ty| self.build_ts_type(ty))
+						.collect();
+
+					self.apply_type_args(
+						parent_instance,
+						parent_class.type_params(),
+						parent_instance,
+						&type_args,
+						super_type_params.span,
+					)
+				}
+				None => {
+					let type_args = self.infer_type_args(parent_class.type_params(), &[], &[]);
+					let subst = self.type_param_subst(parent_class.type_params(), &type_args);
+
+					self.instantiate(parent_instance, &subst)
 				}
+			};
+
+			// Copy parent fields to child
+			if let crate::TyKind::Interface(parent_instance) = parent_instance.kind() {
+				instance_members.inherit(parent_instance);
 			}
+			static_members.inherit(&parent_class.statics());
+			instance_members.abstract_members = parent_class.abstract_members().clone();
 		}
 
 		for member in &class.body {
 			match member {
 				ClassMember::Constructor(consructor) => {
-					ctor = Some(self.check_constructor(consructor));
+					let params = self.constructor_params(consructor);
+
+					// parameter properties are fields of the instance too
+					for param in &consructor.params {
+						let ParamOrTsParamProp::TsParamProp(TsParamProp {
+							accessibility,
+							readonly: is_readonly,
+							param,
+							..
+						}) = param
+						else {
+							continue;
+						};
+						let ident = Self::param_prop_ident(param);
+						let name = Symbol::new(ident.to_id());
+						let ty = params
+							.bindings()
+							.iter()
+							.find_map(|(binding, ty)| (*binding == name).then_some(*ty))
+							.unwrap_or(self.constants.err);
+						let key = ident.sym.clone();
+
+						spans.insert(key.clone(), ident.span);
+						instance_members.insert_prop(key.clone(), ty, ident.optional, *is_readonly);
+						instance_members.set_abstract(key.clone(), None);
+						instance_members.set_visibility(key, self.visibility(*accessibility));
+					}
+
+					ctor_params.push(params);
 				}
-				ClassMember::ClassProp(prop) => {
-					let key = match &prop.key {
+				ClassMember::StaticBlock(_) => {}
+				ClassMember::ClassProp(ClassProp {
+					key,
+					value,
+					type_ann,
+					is_static,
+					is_optional,
+					is_abstract,
+					accessibility,
+					readonly: is_readonly,
+					span,
+					..
+				}) => {
+					let key_span = key.span();
+					let key = match key {
 						PropName::Ident(ident) => ident.sym.clone(),
-						_ => todo!("{:#?}", prop.key),
+						_ => todo!("{:#?}", key),
 					};
-
-					let ty = prop
-						.type_ann
-						.as_ref()
-						.map(|type_ann| self.build_ts_type(&type_ann.type_ann));
-					let init = prop
-						.value
-						.as_ref()
-						.map(|value| self.check_expr(value, None));
-
-					let ty = match (ty, init) {
-						(Some(ty), Some(init)) => {
-							if !self.satisfies(ty, init.ty) {
-								self.raise_type_error(ty, init.ty, prop.span);
+					let ty = match (is_static, type_ann, value) {
+						(false, None, Some(value)) => {
+							unannotated.push((key.clone(), value.as_ref(), *span));
+							self.constants.err
+						}
+						_ => self.prop_type(type_ann.as_deref(), value.as_deref(), *span),
+					};
+					let is_abstract = *is_abstract
+						&& match (class.is_abstract, value) {
+							(false, _) => {
+								self.add_error_with_span(
+									ErrorKind::AbstractPropertyInConcreteClass,
+									key_span,
+								);
+								false
+							}
+							(true, Some(_)) => {
+								self.add_error_with_span(
+									ErrorKind::AbstractPropertyWithInit(key.clone()),
+									key_span,
+								);
+								true
 							}
-							ty
+							(true, None) => true,
+						};
+
+					let members = match is_static {
+						true => &mut static_members,
+						false => {
+							spans.insert(key.clone(), key_span);
+							&mut instance_members
 						}
-						(Some(ty), None) => ty,
-						(None, Some(init)) => init.ty,
-						(None, None) => {
-							self.add_error_with_span(
-								ErrorKind::ClassPropMissingTypeAnnOrInit,
-								prop.span,
-							);
+					};
+					members.insert_prop(key.clone(), ty, *is_optional, *is_readonly);
+					members.set_abstract(key.clone(), is_abstract.then(|| self.name.clone()));
+					members.set_visibility(key, self.visibility(*accessibility));
+				}
+				ClassMember::PrivateProp(PrivateProp {
+					key,
+					value,
+					type_ann,
+					is_static,
+					is_optional,
+					readonly: is_readonly,
+					span,
+					..
+				}) => {
+					let key_span = key.span;
+					let key = BaseChecker::private_key(key);
+					let ty = match (is_static, type_ann, value) {
+						(false, None, Some(value)) => {
+							unannotated.push((key.clone(), value.as_ref(), *span));
 							self.constants.err
 						}
+						_ => self.prop_type(type_ann.as_deref(), value.as_deref(), *span),
 					};
 
-					field_tys.insert(key, ty);
+					let members = match is_static {
+						true => &mut static_members,
+						false => {
+							spans.insert(key.clone(), key_span);
+							&mut instance_members
+						}
+					};
+					members.insert_prop(key.clone(), ty, *is_optional, *is_readonly);
+					members.set_visibility(key, Some(Visibility::Private(self.name.clone())));
 				}
-				ClassMember::Method(method) => {
-					let key = match &method.key {
+				ClassMember::Method(ClassMethod {
+					key,
+					function,
+					kind,
+					accessibility,
+					is_static,
+					is_abstract,
+					..
+				}) => {
+					let key_span = key.span();
+					let key = match key {
 						PropName::Ident(ident) => ident.sym.clone(),
-						_ => todo!("{:#?}", method.key),
+						_ => todo!("{:#?}", key),
 					};
-
-					let mut params = vec![];
-					for param in &method.function.params {
-						match &param.pat {
-							Pat::Ident(ident) => {
-								let name = Symbol::new(ident.to_id());
-
-								let ty = match &ident.type_ann {
-									Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
-									None => {
-										self.add_error_with_span(
-											ErrorKind::ParamMissingTypeAnn,
-											ident.span,
-										);
-										self.constants.err
-									}
-								};
-
-								params.push((name, ty));
+					let is_abstract = *is_abstract
+						&& match (class.is_abstract, &function.body) {
+							(false, _) => {
+								self.add_error_with_span(
+									ErrorKind::AbstractMethodInConcreteClass,
+									key_span,
+								);
+								false
+							}
+							(true, Some(_)) => {
+								self.add_error_with_span(
+									ErrorKind::AbstractMethodWithBody(key.clone()),
+									key_span,
+								);
+								true
 							}
-							_ => todo!("{:#?}", param),
+							(true, None) => true,
+						};
+					let (params, ret) = self.method_signature(function);
+					let ty = self.method_type(*kind, &params, ret);
+					signatures.push((params, ret));
+
+					let members = match is_static {
+						true => &mut static_members,
+						false => {
+							spans.insert(key.clone(), key_span);
+							&mut instance_members
 						}
-					}
-
-					let ret = match &method.function.return_type {
-						Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
-						None => {
-							// NOTE: seal does't infer the return type
-							self.constants.void
+					};
+					members.insert_method(key.clone(), *kind, ty);
+					members.set_abstract(key.clone(), is_abstract.then(|| self.name.clone()));
+					members.set_visibility(key, self.visibility(*accessibility));
+				}
+				ClassMember::PrivateMethod(PrivateMethod {
+					key,
+					function,
+					kind,
+					is_static,
+					..
+				}) => {
+					let (params, ret) = self.method_signature(function);
+					let ty = self.method_type(*kind, &params, ret);
+					signatures.push((params, ret));
+					let key_span = key.span;
+					let key = BaseChecker::private_key(key);
+
+					let members = match is_static {
+						true => &mut static_members,
+						false => {
+							spans.insert(key.clone(), key_span);
+							&mut instance_members
 						}
 					};
+					members.insert_method(key.clone(), *kind, ty);
+					members.set_visibility(key, Some(Visibility::Private(self.name.clone())));
+				}
+				ClassMember::TsIndexSignature(sig) => {
+					let members = match sig.is_static {
+						true => &mut static_members,
+						false => &mut instance_members,
+					};
+					self.build_index_signature(sig, &mut members.index);
+				}
+				_ => todo!("{:#?}", member),
+			}
+		}
 
-					let checker = FunctionChecker::new(self.tcx, params, ret);
-					let result = checker.check_function(&method.function);
+		// the initializers see `this` as an instance with the members inferred so far
+		for (key, value, span) in unannotated {
+			let partial = Rc::new(instance_members.to_interface(&self.name, type_params.clone()));
+			self.bind_this(self.tcx.new_type_parameter(TypeParameter::new(
+				Symbol::new_this(),
+				Some(self.tcx.new_interface(partial)),
+				None,
+			)));
+			let ty = self.prop_type(None, Some(value), span);
+			instance_members.fields.insert(key, ty);
+		}
 
-					for error in result.errors {
-						self.add_error_with_span(error.kind, error.span);
-					}
+		for members in [&instance_members, &static_members] {
+			self.check_index_signatures(
+				&members.fields,
+				&members.optional,
+				&members.index,
+				self.span,
+			);
+		}
 
-					field_tys.insert(key, self.tcx.new_function(result.ty));
-					methods.push(result.def);
-				}
-				_ => todo!("{:#?}", member),
+		let abstract_members = std::mem::take(&mut instance_members.abstract_members);
+		if !class.is_abstract {
+			for (key, base) in &abstract_members {
+				self.add_error_with_span(
+					ErrorKind::AbstractMemberNotImplemented(
+						self.name.clone(),
+						key.clone(),
+						base.clone(),
+					),
+					self.span,
+				);
+			}
+		}
+
+		let interface = Rc::new(instance_members.to_interface(&self.name, type_params.clone()));
+		let statics = Rc::new(static_members.to_interface(&self.name, vec![]));
+
+		let instance = self.tcx.new_interface(interface.clone());
+
+		// `this` in the bodies is the object being checked, which is an instance of the class
+		let this = self.tcx.new_type_parameter(TypeParameter::new(
+			Symbol::new_this(),
+			Some(instance),
+			None,
+		));
+		self.bind_this(this);
+
+		let mut ctor = None;
+		let mut ctor_params = ctor_params.into_iter();
+		for member in &class.body {
+			if let ClassMember::Constructor(consructor) = member {
+				let params = ctor_params.next().unwrap();
+				ctor = Some(self.check_constructor(consructor, params, &type_params, this));
 			}
 		}
 
@@ -173,58 +504,381 @@ impl<'tcx> ClassChecker<'tcx> {
 			None => (None, None),
 		};
 
+		let class_ty = if let Some(parent_ty) = parent {
+			crate::kind::Class::new_with_parent(ctor_ty, interface.clone(), parent_ty)
+		} else {
+			crate::kind::Class::new(ctor_ty, interface.clone())
+		}
+		.with_type_params(type_params.clone())
+		.with_statics(statics.clone());
+		let class_ty = self.tcx.new_class(match class.is_abstract {
+			true => class_ty.with_abstract(abstract_members),
+			false => class_ty,
+		});
+		// `this` in static members is the class itself
+		let this_of = |is_static: bool| match is_static {
+			true => class_ty,
+			false => this,
+		};
+		let members_of = |is_static: bool| match is_static {
+			true => statics.clone(),
+			false => interface.clone(),
+		};
+
+		let mut signatures = signatures.into_iter();
+		for member in &class.body {
+			match member {
+				ClassMember::ClassProp(ClassProp {
+					key: PropName::Ident(ident),
+					value: Some(value),
+					type_ann: Some(_),
+					is_static,
+					span,
+					..
+				}) => {
+					let ty = members_of(*is_static).fields()[&ident.sym];
+					self.check_prop_init(ty, value, *span, this_of(*is_static));
+				}
+				ClassMember::PrivateProp(PrivateProp {
+					key,
+					value: Some(value),
+					type_ann: Some(_),
+					is_static,
+					span,
+					..
+				}) => {
+					let ty = members_of(*is_static).fields()[&BaseChecker::private_key(key)];
+					self.check_prop_init(ty, value, *span, this_of(*is_static));
+				}
+				ClassMember::Method(ClassMethod {
+					function,
+					is_static,
+					is_abstract,
+					..
+				})
+				| ClassMember::PrivateMethod(PrivateMethod {
+					function,
+					is_static,
+					is_abstract,
+					..
+				}) => {
+					let (params, ret) = signatures.next().unwrap();
+					// an abstract method has no body to check
+					if *is_abstract && function.body.is_none() {
+						continue;
+					}
+					let this = this_of(*is_static);
+					methods.push(self.check_method_body(function, params, ret, &type_params, this));
+				}
+				ClassMember::StaticBlock(StaticBlock { body, .. }) => {
+					methods.push(self.check_static_block(body, &type_params, class_ty));
+				}
+				_ => {}
+			}
+		}
+
+		for implemented in &class.implements {
+			self.check_implements(implemented, instance, &spans);
+		}
+
 		ClassCheckerResult {
-			ty: if let Some(parent_ty) = parent {
-				crate::kind::Class::new_with_parent(
-					ctor_ty,
-					Rc::new(crate::kind::Interface::new(self.name.clone(), field_tys)),
-					parent_ty,
-				)
-			} else {
-				crate::kind::Class::new(
-					ctor_ty,
-					Rc::new(crate::kind::Interface::new(self.name.clone(), field_tys)),
-				)
-			},
+			ty: class_ty,
 			def: sir::Class { ctor, methods },
 			errors: self.base.errors.into_inner(),
 		}
 	}
 
-	pub fn check_constructor(
+	/// Reports the reads of `this.key` in the initializer of a field declared before `key`, which
+	/// is not initialized yet
+	fn check_init_order(&self, class: &Class) {
+		let fields: Vec<_> = class
+			.body
+			.iter()
+			.filter_map(|member| match member {
+				ClassMember::ClassProp(ClassProp {
+					key: PropName::Ident(ident),
+					value,
+					is_static: false,
+					..
+				}) => Some((ident.sym.clone(), value.as_deref())),
+				ClassMember::PrivateProp(PrivateProp {
+					key,
+					value,
+					is_static: false,
+					..
+				}) => Some((BaseChecker::private_key(key), value.as_deref())),
+				_ => None,
+			})
+			.collect();
+
+		for (index, (_, value)) in fields.iter().enumerate() {
+			let Some(value) = value else {
+				continue;
+			};
+			let mut reads = ThisReads::default();
+			value.visit_with(&mut reads);
+
+			for (key, span) in reads.keys {
+				if fields[index..].iter().any(|(field, _)| *field == key) {
+					self.add_error_with_span(ErrorKind::UsedBeforeInitialization(key), span);
+				}
+			}
+		}
+	}
+
+	/// The type of a field of the class, which needs a type annotation or an initializer. The
+	/// initializer of an annotated field is checked with the bodies of the methods.
+	fn prop_type(
 		&self,
-		consructor: &Constructor,
-	) -> (crate::kind::Function<'tcx>, sir::Func) {
-		let mut params = vec![];
-		for param in &consructor.params {
-			match param {
-				ParamOrTsParamProp::Param(param) => match &param.pat {
-					Pat::Ident(ident) => {
-						let name = Symbol::new(ident.to_id());
+		type_ann: Option<&TsTypeAnn>,
+		value: Option<&Expr>,
+		span: Span,
+	) -> crate::Ty<'tcx> {
+		match (type_ann, value) {
+			(Some(type_ann), _) => self.build_ts_type(&type_ann.type_ann),
+			(None, Some(value)) => self.check_expr(value, None).ty,
+			(None, None) => {
+				self.add_error_with_span(ErrorKind::ClassPropMissingTypeAnnOrInit, span);
+				self.constants.err
+			}
+		}
+	}
 
-						let ty = match &ident.type_ann {
-							Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
-							None => {
-								self.add_error_with_span(
-									ErrorKind::ParamMissingTypeAnn,
-									ident.span,
-								);
-								self.constants.err
-							}
-						};
+	fn check_prop_init(
+		&self,
+		ty: crate::Ty<'tcx>,
+		value: &Expr,
+		span: Span,
+		this: crate::Ty<'tcx>,
+	) {
+		self.bind_this(this);
+		let init = self.check_expr(value, Some(ty));
+
+		if !self.satisfies(ty, init.ty) {
+			self.raise_type_error(ty, init.ty, span);
+		}
+	}
+
+	fn method_signature(&self, function: &Function) -> (Params<'tcx>, crate::Ty<'tcx>) {
+		let params = self.build_params(function.params.iter().map(|param| &param.pat), &|pat| {
+			self.param_missing_type_ann(pat)
+		});
+
+		let ret = match &function.return_type {
+			Some(type_ann) => self.build_ts_type(&type_ann.type_ann),
+			None => {
+				// NOTE: seal does't infer the return type
+				self.constants.void
+			}
+		};
+
+		(params, ret)
+	}
+
+	/// The type of a method as a member: a getter gives the type of the property, and a setter the
+	/// type of its parameter
+	fn method_type(
+		&self,
+		kind: MethodKind,
+		params: &Params<'tcx>,
+		ret: crate::Ty<'tcx>,
+	) -> crate::Ty<'tcx> {
+		match kind {
+			MethodKind::Method => self.tcx.new_function(params.clone().into_function(ret)),
+			MethodKind::Getter => ret,
+			MethodKind::Setter => params
+				.params
+				.first()
+				.map_or(self.constants.err, |(_, ty)| *ty),
+		}
+	}
+
+	fn check_method_body(
+		&self,
+		function: &Function,
+		params: Params<'tcx>,
+		ret: crate::Ty<'tcx>,
+		type_params: &[crate::Ty<'tcx>],
+		this: crate::Ty<'tcx>,
+	) -> sir::Func {
+		// returning `this` satisfies a `this` return type
+		let ret = self.instantiate(ret, &HashMap::from([(Symbol::new_this(), this)]));
+
+		let checker = FunctionChecker::new(self.tcx, params, ret);
+		checker.set_type_param_bindings(type_params);
+		checker.set_class_scope(self.nested_class_scope());
+		checker.bind_this(this);
+		let result = checker.check_function(function);
+
+		for error in result.errors {
+			self.add_error_with_span(error.kind, error.span);
+		}
+
+		result.def
+	}
+
+	/// Checks a `static { ... }` block, where `this` is the class
+	fn check_static_block(
+		&self,
+		body: &BlockStmt,
+		type_params: &[crate::Ty<'tcx>],
+		this: crate::Ty<'tcx>,
+	) -> sir::Func {
+		let params = self.build_params([], &|pat| self.param_missing_type_ann(pat));
+		let checker = FunctionChecker::new(self.tcx, params, self.constants.void);
+		checker.set_type_param_bindings(type_params);
+		checker.set_class_scope(self.nested_class_scope());
+		checker.bind_this(this);
+		let result = checker.check_body(body);
+
+		for error in result.errors {
+			self.add_error_with_span(error.kind, error.span);
+		}
+
+		result.def
+	}
+
+	/// The visibility of a member declared with an accessibility modifier
+	fn visibility(&self, accessibility: Option<Accessibility>) -> Option<Visibility> {
+		match accessibility? {
+			Accessibility::Public => None,
+			Accessibility::Protected => Some(Visibility::Protected(self.name.clone())),
+			Accessibility::Private => Some(Visibility::Private(self.name.clone())),
+		}
+	}
+
+	/// Checks that the instance type satisfies an interface the class implements. A member is
+	/// reported where the class declares it, or at the class name when it's missing or inherited.
+	fn check_implements(
+		&self,
+		implemented: &TsExprWithTypeArgs,
+		instance: crate::Ty<'tcx>,
+		spans: &BTreeMap<Atom, Span>,
+	) {
+		let expected = self.build_ts_type(&BaseChecker::base_type(implemented));
+		let empty = BTreeMap::new();
+		let (fields, optional, visibility, index, signatures) = match expected.kind() {
+			crate::TyKind::Object(obj) => (
+				obj.fields(),
+				obj.optional(),
+				&empty,
+				obj.index(),
+				obj.signatures(),
+			),
+			crate::TyKind::Interface(interface) => (
+				interface.fields(),
+				interface.optional(),
+				interface.visibility(),
+				interface.index(),
+				interface.signatures(),
+			),
+			crate::TyKind::Err => return,
+			_ => {
+				self.add_error_with_span(ErrorKind::ClassImplementsNonObject, implemented.span);
+				return;
+			}
+		};
+		let crate::TyKind::Interface(actual) = instance.kind() else {
+			unreachable!("the instance type of a class is an interface")
+		};
+
+		for (key, expected_ty) in fields {
+			let is_optional = optional.contains(key);
+			let span = spans.get(key).copied().unwrap_or(self.span);
 
-						params.push((name, ty));
+			match actual.fields().get(key) {
+				Some(_) if actual.visibility().get(key) != visibility.get(key) => {
+					self.add_error_with_span(
+						ErrorKind::ClassImplementsVisibility(
+							instance,
+							expected,
+							key.clone(),
+							actual.visibility().get(key).cloned(),
+							visibility.get(key).cloned(),
+						),
+						self.span,
+					);
+				}
+				Some(actual_ty) => {
+					let expected_ty = self.optional_prop_ty(*expected_ty, is_optional);
+					if !self.satisfies(expected_ty, *actual_ty) {
+						self.add_error_with_span(
+							ErrorKind::PropertyNotAssignableToBase(
+								key.clone(),
+								instance,
+								expected,
+								Box::new(ErrorKind::NotAssignable(expected_ty, *actual_ty)),
+							),
+							span,
+						);
 					}
-					_ => todo!("{:#?}", param),
-				},
-				_ => todo!("{:#?}", param),
+				}
+				None if is_optional => {}
+				None => {
+					self.add_error_with_span(
+						ErrorKind::ClassIncorrectlyImplements(instance, expected, key.clone()),
+						self.span,
+					);
+				}
 			}
 		}
 
+		if !self.satisfies_index(index, instance)
+			|| !self.satisfies_signatures(signatures, instance)
+		{
+			self.add_error_with_span(
+				ErrorKind::ClassImplementsSignatures(instance, expected),
+				self.span,
+			);
+		}
+	}
+
+	/// The parameters of a constructor, where a parameter property like `private x: T` is a
+	/// parameter as well
+	fn constructor_params(&self, consructor: &Constructor) -> Params<'tcx> {
+		let pats: Vec<_> = consructor
+			.params
+			.iter()
+			.map(|param| match param {
+				ParamOrTsParamProp::Param(param) => param.pat.clone(),
+				ParamOrTsParamProp::TsParamProp(TsParamProp { param, .. }) => match param {
+					TsParamPropParam::Ident(ident) => Pat::Ident(ident.clone()),
+					TsParamPropParam::Assign(assign) => Pat::Assign(assign.clone()),
+				},
+			})
+			.collect();
+
+		self.build_params(&pats, &|pat| self.param_missing_type_ann(pat))
+	}
+
+	/// The name of a parameter property
+	fn param_prop_ident(param: &TsParamPropParam) -> &Ident {
+		match param {
+			TsParamPropParam::Ident(ident) => &ident.id,
+			TsParamPropParam::Assign(AssignPat { left, .. }) => match left.as_ref() {
+				Pat::Ident(ident) => &ident.id,
+				_ => unreachable!("a parameter property is an identifier"),
+			},
+		}
+	}
+
+	pub fn check_constructor(
+		&self,
+		consructor: &Constructor,
+		params: Params<'tcx>,
+		type_params: &[crate::Ty<'tcx>],
+		this: crate::Ty<'tcx>,
+	) -> (crate::kind::Function<'tcx>, sir::Func) {
 		// NOTE: Constructor cannot have return stmt in seal, so we should not use FunctionChecker
 		let checker = BaseChecker::new(self.tcx);
-
-		for (name, ty) in &params {
+		checker.set_type_param_bindings(type_params);
+		checker.set_class_scope(self.class_scope().map(|scope| ClassScope {
+			is_constructor: true,
+			..scope
+		}));
+		checker.bind_this(this);
+
+		for (name, ty) in params.bindings() {
 			let param = checker.add_local(*ty, Value::Param);
 			checker.set_binding(name, Some(param), *ty, false);
 		}
@@ -234,7 +888,7 @@ impl<'tcx> ClassChecker<'tcx> {
 			_ => {
 				self.add_error_with_span(ErrorKind::MissingBody, consructor.span);
 				return (
-					crate::kind::Function::new(params, self.constants.void),
+					params.into_function(self.constants.void),
 					sir::Func {
 						locals: HashMap::new(),
 					},
@@ -256,10 +910,38 @@ impl<'tcx> ClassChecker<'tcx> {
 		}
 
 		(
-			crate::kind::Function::new(params, self.constants.void),
+			params.into_function(self.constants.void),
 			sir::Func {
 				locals: checker.locals.into_inner(),
 			},
 		)
 	}
 }
+
+/// The members read from `this` by an expression, except in the functions it creates
+#[derive(Default)]
+struct ThisReads {
+	keys: Vec<(Atom, Span)>,
+}
+
+impl Visit for ThisReads {
+	fn visit_member_expr(&mut self, member: &MemberExpr) {
+		if let Expr::This(_) = member.obj.as_ref() {
+			match &member.prop {
+				MemberProp::Ident(ident) => self.keys.push((ident.sym.clone(), ident.span)),
+				MemberProp::PrivateName(name) => {
+					self.keys.push((BaseChecker::private_key(name), name.span))
+				}
+				MemberProp::Computed(_) => {}
+			}
+		}
+
+		member.visit_children_with(self);
+	}
+
+	fn visit_function(&mut self, _: &Function) {}
+
+	fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+	fn visit_class(&mut self, _: &Class) {}
+}
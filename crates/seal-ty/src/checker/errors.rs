@@ -1,9 +1,13 @@
-use std::fmt::Display;
+use std::{fmt::Display, ops::RangeInclusive};
 
 use swc_atoms::Atom;
 use swc_common::Span;
 
-use crate::{Ty, TyKind, symbol::Symbol};
+use crate::{
+	Ty, TyKind,
+	kind::{NumberLit, Visibility},
+	symbol::Symbol,
+};
 
 #[derive(Debug)]
 pub struct Error<'tcx> {
@@ -36,19 +40,49 @@ pub enum ErrorKind<'tcx> {
 	UnexpectedReturn,
 	/// TS(1155):
 	ConstMissingInit,
+	/// TS(1016)
+	RequiredParamAfterOptional,
+	/// TS(1061)
+	EnumMemberMustHaveInitializer,
+	/// TS(1182)
+	DestructuringMissingInit,
 	/// TS(1196)
 	CatchParameterCannotHaveTypeAnnotation,
+	/// TS(1244)
+	AbstractMethodInConcreteClass,
+	/// TS(1245)
+	AbstractMethodWithBody(Atom),
+	/// TS(1253)
+	AbstractPropertyInConcreteClass,
+	/// TS(1267)
+	AbstractPropertyWithInit(Atom),
+	/// TS(1268)
+	InvalidIndexSignatureParam,
+	/// TS(1338)
+	InferOutsideConditional,
+	/// TS(1354)
+	ReadonlyModifierNotPermitted,
 
 	/// TS(2304)
 	CannotFindName(Symbol),
+	/// TS(2312)
+	InterfaceExtendsNonObject,
+	/// TS(2320): the interface, the bases and the property they conflict on
+	InterfaceBasesConflict(Symbol, Ty<'tcx>, Ty<'tcx>, Atom),
 	/// TS(2322)
 	NotAssignable(Ty<'tcx>, Ty<'tcx>),
 	/// TS(2324)
 	PropertyMissing(Atom, Ty<'tcx>),
 	/// TS(2326)
 	PropertyTypesIncompatible(Atom, Ty<'tcx>, Ty<'tcx>),
+	/// TS(2314), TS(2707)
+	GenericRequiresTypeArgs(Ty<'tcx>, usize, usize),
+	/// TS(2315)
+	NotGeneric(Ty<'tcx>),
 	/// TS(2339)
 	PropertyDoesNotExist(Ty<'tcx>, Atom),
+	/// TS(2344)
+	TypeArgNotSatisfyConstraint(Ty<'tcx>, Ty<'tcx>),
 	/// TS(2345)
 	ArgumentNotAssignable(Ty<'tcx>, Ty<'tcx>),
 	/// TS(2349)
@@ -59,21 +93,92 @@ pub enum ErrorKind<'tcx> {
 	UnexpectedVoid,
 	/// TS(2367)
 	NoOverlap(Ty<'tcx>, Ty<'tcx>),
+	/// TS(2411), TS(2413)
+	PropertyNotAssignableToIndex(Atom, Ty<'tcx>, Ty<'tcx>, Ty<'tcx>),
+	/// TS(2370)
+	RestParamNotArray,
+	/// TS(2391)
+	FunctionImplementationMissing,
+	/// TS(2394)
+	OverloadNotCompatible,
+	/// TS(2341): the member and the class declaring it
+	PrivateMemberNotAccessible(Atom, Symbol),
+	/// TS(2416): the property, the class, the base and why the property is incompatible
+	PropertyNotAssignableToBase(Atom, Ty<'tcx>, Ty<'tcx>, Box<ErrorKind<'tcx>>),
+	/// TS(2420): the class, the interface and the missing property
+	ClassIncorrectlyImplements(Ty<'tcx>, Ty<'tcx>, Atom),
+	/// TS(2420): the class, the interface, the property and its visibility in each of them
+	ClassImplementsVisibility(
+		Ty<'tcx>,
+		Ty<'tcx>,
+		Atom,
+		Option<Visibility>,
+		Option<Visibility>,
+	),
+	/// TS(2420): the class and the interface whose index or call signatures it doesn't have
+	ClassImplementsSignatures(Ty<'tcx>, Ty<'tcx>),
+	/// TS(2422)
+	ClassImplementsNonObject,
+	/// TS(2428)
+	InterfaceTypeParamsDiffer(Symbol),
+	/// TS(2430): the interface, the base and the incompatible property
+	InterfaceIncorrectlyExtends(Symbol, Ty<'tcx>, Atom),
+	/// TS(2445): the member and the class declaring it
+	ProtectedMemberNotAccessible(Atom, Symbol),
 	/// TS(2454)
 	UsedBeforeAssigned(Symbol),
+	/// TS(2493)
+	TupleIndexOutOfBounds(Ty<'tcx>, usize, NumberLit),
+	/// TS(2511)
+	AbstractClassInstantiation,
+	/// TS(2515): the class, the member and the class declaring it
+	AbstractMemberNotImplemented(Symbol, Atom, Symbol),
+	/// TS(2537)
+	NoMatchingIndexSignature(Ty<'tcx>, Ty<'tcx>),
 	/// TS(2538)
 	TypeCannotBeUsedAsIndexType(Ty<'tcx>),
 	/// TS(2540)
 	CannotAssignToReadOnlyProperty(Atom),
-	/// TS(2554)
-	WrongNumArgs(usize, usize),
+	/// TS(2461)
+	NotArrayType(Ty<'tcx>),
+	/// TS(2474)
+	ConstEnumMemberInitializer,
+	/// TS(2475)
+	ConstEnumOnlyInAccess,
+	/// TS(2542)
+	ReadonlyIndexSignature(Ty<'tcx>),
+	/// TS(2526)
+	ThisTypeOutsideClass,
+	/// TS(2554): the accepted number of arguments and the given number
+	WrongNumArgs(RangeInclusive<usize>, usize),
+	/// TS(2555)
+	TooFewArgs(usize, usize),
+	/// TS(2556)
+	SpreadArgNotRest,
+	/// TS(2558)
+	WrongNumTypeArgs(usize, usize),
 	/// TS(2588)
 	CannotAssignToConst(Symbol),
+	/// TS(2694)
+	NoExportedMember(Symbol, Atom),
+	/// TS(2698)
+	SpreadTypesFromObjectTypes,
+	/// TS(2700)
+	RestTypesFromObjectTypes,
+	/// TS(2717): the property, its type and the type it is redeclared with
+	SubsequentPropertyType(Atom, Ty<'tcx>, Ty<'tcx>),
+	/// TS(2729)
+	UsedBeforeInitialization(Atom),
+	/// TS(2683)
+	ThisImplicitlyAny,
+	/// TS(2769): the error of the overload closest to matching, its index and the number of overloads
+	NoOverloadMatches(usize, usize, Ty<'tcx>, Box<ErrorKind<'tcx>>),
+	/// TS(18013): the member and the class declaring it
+	PrivateIdentifierNotAccessible(Atom, Symbol),
 	/// Custom error for binary operator type mismatch
 	BinaryOperatorTypeMismatch(swc_ecma_ast::BinaryOp, Ty<'tcx>, Ty<'tcx>),
 	/// Custom error for extending non-class type
 	ExtendsNonClass(Ty<'tcx>),
-	InvalidNumberLiteral(f64),
 }
 
 impl Display for ErrorKind<'_> {
@@ -105,6 +210,20 @@ impl Display for ErrorKind<'_> {
 			CannotFindName(name) => {
 				write!(f, "Cannot find name '{}'.", name)
 			}
+			// TS(2312)
+			InterfaceExtendsNonObject => {
+				write!(
+					f,
+					"An interface can only extend an object type or intersection of object types with statically known members."
+				)
+			}
+			// TS(2320)
+			InterfaceBasesConflict(name, first, second, key) => {
+				write!(
+					f,
+					"Interface '{name}' cannot simultaneously extend types '{first}' and '{second}'.\n  Named property '{key}' of types '{first}' and '{second}' are not identical."
+				)
+			}
 			// TS(1155)
 			ConstMissingInit => {
 				write!(f, "'const' declarations must be initialized.")
@@ -115,18 +234,27 @@ impl Display for ErrorKind<'_> {
 				// Otherwise show the widened type for better error messages
 				let actual = match (expected.kind(), actual.kind()) {
 					// If expected is a union and actual is a literal, show the literal
-					(Union(_), String(Some(_)) | Number(Some(_)) | Boolean(Some(_))) => {
+					(
+						Union(_),
+						String(Some(_)) | Number(Some(_)) | BigInt(Some(_)) | Boolean(Some(_)),
+					) => {
 						format!("{}", actual)
 					}
 
-					// If expected is a literal and actual is a literal, show the literal
-					(String(Some(_)), String(Some(_))) => format!("{}", actual),
+					// If expected is a literal (or a template of literal text) and actual is a literal, show the literal
+					(String(Some(_)) | Template(_), String(Some(_))) => format!("{}", actual),
 					(Number(Some(_)), Number(Some(_))) => format!("{}", actual),
+					(BigInt(Some(_)), BigInt(Some(_))) => format!("{}", actual),
 					(Boolean(Some(_)), Boolean(Some(_))) => format!("{}", actual),
+					// enums only accept their own literal values
+					(Enum(_) | EnumMember(_), String(Some(_)) | Number(Some(_))) => {
+						format!("{}", actual)
+					}
 
 					// For other cases, widen literal types for better error messages
 					(_, String(Some(_))) => "string".to_string(),
 					(_, Number(Some(_))) => "number".to_string(),
+					(_, BigInt(Some(_))) => "bigint".to_string(),
 					(_, Boolean(Some(_))) => "boolean".to_string(),
 
 					// For non-literal types, use the actual type
@@ -160,10 +288,32 @@ impl Display for ErrorKind<'_> {
 					"This comparison appears to be unintentional because the types '{left}' and '{right}' have no overlap."
 				)
 			}
+			// TS(2314), TS(2707)
+			GenericRequiresTypeArgs(ty, required, total) => {
+				if required == total {
+					write!(f, "Generic type '{ty}' requires {total} type argument(s).")
+				} else {
+					write!(
+						f,
+						"Generic type '{ty}' requires between {required} and {total} type arguments."
+					)
+				}
+			}
+			// TS(2315)
+			NotGeneric(ty) => {
+				write!(f, "Type '{ty}' is not generic.")
+			}
 			// TS(2339)
 			PropertyDoesNotExist(ty, key) => {
 				write!(f, "Property '{key}' does not exist on type '{ty}'.")
 			}
+			// TS(2344)
+			TypeArgNotSatisfyConstraint(ty, constraint) => {
+				write!(
+					f,
+					"Type '{ty}' does not satisfy the constraint '{constraint}'."
+				)
+			}
 			// TS(2345)
 			ArgumentNotAssignable(expected, actual) => {
 				write!(
@@ -176,8 +326,36 @@ impl Display for ErrorKind<'_> {
 				write!(f, "This expression is not constructable.")
 			}
 			// TS(2554)
-			WrongNumArgs(expected, actual) => {
-				write!(f, "Expected {expected} arguments, but got {actual}.")
+			WrongNumArgs(expected, actual) => match expected.start() == expected.end() {
+				true => write!(
+					f,
+					"Expected {} arguments, but got {actual}.",
+					expected.start()
+				),
+				false => write!(
+					f,
+					"Expected {}-{} arguments, but got {actual}.",
+					expected.start(),
+					expected.end()
+				),
+			},
+			// TS(2555)
+			TooFewArgs(expected, actual) => {
+				write!(
+					f,
+					"Expected at least {expected} arguments, but got {actual}."
+				)
+			}
+			// TS(2556)
+			SpreadArgNotRest => {
+				write!(
+					f,
+					"A spread argument must either have a tuple type or be passed to a rest parameter."
+				)
+			}
+			// TS(2558)
+			WrongNumTypeArgs(expected, actual) => {
+				write!(f, "Expected {expected} type arguments, but got {actual}.")
 			}
 			// TS(1108)
 			UnexpectedReturn => {
@@ -190,6 +368,45 @@ impl Display for ErrorKind<'_> {
 			CannotAssignToConst(name) => {
 				write!(f, "Cannot assign to '{name}' because it is a constant.")
 			}
+			// TS(2769)
+			NoOverloadMatches(index, len, signature, error) => {
+				write!(
+					f,
+					"No overload matches this call. Overload {} of {len}, '",
+					index + 1
+				)?;
+				match signature.kind() {
+					Function(function) => function.fmt_signature(f, ": ")?,
+					_ => write!(f, "{signature}")?,
+				}
+				write!(f, "', gave the following error. {error}")
+			}
+			// TS(2698)
+			SpreadTypesFromObjectTypes => {
+				write!(f, "Spread types may only be created from object types.")
+			}
+			// TS(2700)
+			RestTypesFromObjectTypes => {
+				write!(f, "Rest types may only be created from object types.")
+			}
+			// TS(2717)
+			SubsequentPropertyType(key, expected, actual) => {
+				write!(
+					f,
+					"Subsequent property declarations must have the same type.  Property '{key}' must be of type '{expected}', but here has type '{actual}'."
+				)
+			}
+			// TS(2729)
+			UsedBeforeInitialization(key) => {
+				write!(f, "Property '{key}' is used before its initialization.")
+			}
+			// TS(2694)
+			NoExportedMember(namespace, member) => {
+				write!(
+					f,
+					"Namespace '{namespace}' has no exported member '{member}'."
+				)
+			}
 			// TS(2349)
 			NotCallable(ty) => {
 				write!(
@@ -197,10 +414,161 @@ impl Display for ErrorKind<'_> {
 					"This expression is not callable.\n  Type '{ty}' has no call signatures."
 				)
 			}
+			// TS(2411), TS(2413)
+			PropertyNotAssignableToIndex(prop, ty, key, index) => {
+				write!(
+					f,
+					"Property '{prop}' of type '{ty}' is not assignable to '{key}' index type '{index}'."
+				)
+			}
+			// TS(2370)
+			RestParamNotArray => {
+				write!(f, "A rest parameter must be of an array type.")
+			}
+			// TS(2391)
+			FunctionImplementationMissing => {
+				write!(
+					f,
+					"Function implementation is missing or not immediately following the declaration."
+				)
+			}
+			// TS(2341)
+			PrivateMemberNotAccessible(key, class) => {
+				write!(
+					f,
+					"Property '{key}' is private and only accessible within class '{class}'."
+				)
+			}
+			// TS(2445)
+			ProtectedMemberNotAccessible(key, class) => {
+				write!(
+					f,
+					"Property '{key}' is protected and only accessible within class '{class}' and its subclasses."
+				)
+			}
+			// TS(18013)
+			PrivateIdentifierNotAccessible(key, class) => {
+				write!(
+					f,
+					"Property '{key}' is not accessible outside class '{class}' because it has a private identifier."
+				)
+			}
+			// TS(2526)
+			ThisTypeOutsideClass => {
+				write!(
+					f,
+					"A 'this' type is available only in a non-static member of a class or interface."
+				)
+			}
+			// TS(2683)
+			ThisImplicitlyAny => {
+				write!(
+					f,
+					"'this' implicitly has type 'any' because it does not have a type annotation."
+				)
+			}
+			// TS(2416)
+			PropertyNotAssignableToBase(key, class, base, error) => {
+				write!(
+					f,
+					"Property '{key}' in type '{class}' is not assignable to the same property in base type '{base}'.\n  {error}"
+				)
+			}
+			// TS(2420)
+			ClassIncorrectlyImplements(class, interface, key) => {
+				write!(
+					f,
+					"Class '{class}' incorrectly implements interface '{interface}'.\n  Property '{key}' is missing in type '{class}' but required in type '{interface}'."
+				)
+			}
+			ClassImplementsVisibility(class, interface, key, class_visibility, visibility) => {
+				let modifier = |visibility: &Visibility| match visibility {
+					Visibility::Private(_) => ("private", "not"),
+					Visibility::Protected(_) => ("protected", "public"),
+				};
+				let reason = match (class_visibility, visibility) {
+					(Some(class_visibility), None) => {
+						let (modifier, other) = modifier(class_visibility);
+						format!(
+							"Property '{key}' is {modifier} in type '{class}' but {other} in type '{interface}'."
+						)
+					}
+					(None, Some(visibility)) => {
+						let (modifier, other) = modifier(visibility);
+						format!(
+							"Property '{key}' is {modifier} in type '{interface}' but {other} in type '{class}'."
+						)
+					}
+					_ => format!("Types have separate declarations of a private property '{key}'."),
+				};
+				write!(
+					f,
+					"Class '{class}' incorrectly implements interface '{interface}'.\n  {reason}"
+				)
+			}
+			ClassImplementsSignatures(class, interface) => {
+				write!(
+					f,
+					"Class '{class}' incorrectly implements interface '{interface}'."
+				)
+			}
+			// TS(2422)
+			ClassImplementsNonObject => {
+				write!(
+					f,
+					"A class can only implement an object type or intersection of object types with statically known members."
+				)
+			}
+			// TS(2428)
+			InterfaceTypeParamsDiffer(name) => {
+				write!(
+					f,
+					"All declarations of '{name}' must have identical type parameters."
+				)
+			}
+			// TS(2430)
+			InterfaceIncorrectlyExtends(name, base, key) => {
+				write!(
+					f,
+					"Interface '{name}' incorrectly extends interface '{base}'.\n  Types of property '{key}' are incompatible."
+				)
+			}
+			// TS(2394)
+			OverloadNotCompatible => {
+				write!(
+					f,
+					"This overload signature is not compatible with its implementation signature."
+				)
+			}
 			// TS(2454)
 			UsedBeforeAssigned(name) => {
 				write!(f, "Variable '{name}' is used before being assigned.")
 			}
+			// TS(2493)
+			TupleIndexOutOfBounds(ty, len, index) => {
+				write!(
+					f,
+					"Tuple type '{ty}' of length '{len}' has no element at index '{index}'."
+				)
+			}
+			// TS(2511)
+			AbstractClassInstantiation => {
+				write!(f, "Cannot create an instance of an abstract class.")
+			}
+			// TS(2515)
+			AbstractMemberNotImplemented(class, key, base) => {
+				write!(
+					f,
+					"Non-abstract class '{class}' does not implement inherited abstract member '{key}' from class '{base}'."
+				)
+			}
+			// TS(2537)
+			NoMatchingIndexSignature(ty, index) => {
+				write!(
+					f,
+					"Type '{ty}' has no matching index signature for type '{index}'."
+				)
+			}
 			// TS(2538)
 			TypeCannotBeUsedAsIndexType(ty) => {
 				write!(f, "Type '{ty}' cannot be used as an index type.")
@@ -212,10 +580,96 @@ impl Display for ErrorKind<'_> {
 					"Cannot assign to '{prop}' because it is a read-only property."
 				)
 			}
+			// TS(2461)
+			NotArrayType(ty) => {
+				write!(f, "Type '{ty}' is not an array type.")
+			}
+			// TS(2474)
+			ConstEnumMemberInitializer => {
+				write!(
+					f,
+					"const enum member initializers must be constant expressions."
+				)
+			}
+			// TS(2475)
+			ConstEnumOnlyInAccess => {
+				write!(
+					f,
+					"'const' enums can only be used in property or index access expressions or the right hand side of an import declaration or export assignment or type query."
+				)
+			}
+			// TS(2542)
+			ReadonlyIndexSignature(ty) => {
+				write!(f, "Index signature in type '{ty}' only permits reading.")
+			}
+			// TS(1016)
+			RequiredParamAfterOptional => {
+				write!(
+					f,
+					"A required parameter cannot follow an optional parameter."
+				)
+			}
+			// TS(1061)
+			EnumMemberMustHaveInitializer => {
+				write!(f, "Enum member must have initializer.")
+			}
+			// TS(1182)
+			DestructuringMissingInit => {
+				write!(f, "A destructuring declaration must have an initializer.")
+			}
 			// TS(1196)
 			CatchParameterCannotHaveTypeAnnotation => {
 				write!(f, "Catch clause parameter cannot have a type annotation.")
 			}
+			// TS(1244)
+			AbstractMethodInConcreteClass => {
+				write!(
+					f,
+					"Abstract methods can only appear within an abstract class."
+				)
+			}
+			// TS(1245)
+			AbstractMethodWithBody(key) => {
+				write!(
+					f,
+					"Method '{key}' cannot have an implementation because it is marked abstract."
+				)
+			}
+			// TS(1253)
+			AbstractPropertyInConcreteClass => {
+				write!(
+					f,
+					"Abstract properties can only appear within an abstract class."
+				)
+			}
+			// TS(1267)
+			AbstractPropertyWithInit(key) => {
+				write!(
+					f,
+					"Property '{key}' cannot have an initializer because it is marked abstract."
+				)
+			}
+			// TS(1354)
+			ReadonlyModifierNotPermitted => {
+				write!(
+					f,
+					"'readonly' type modifier is only permitted on array and tuple literal types."
+				)
+			}
+			// TS(1268)
+			InvalidIndexSignatureParam => {
+				write!(
+					f,
+					"An index signature parameter type must be 'string', 'number', 'symbol', or a template literal type."
+				)
+			}
+			// TS(1338)
+			InferOutsideConditional => {
+				write!(
+					f,
+					"'infer' declarations are only permitted in the 'extends' clause of a conditional type."
+				)
+			}
 			// Custom errors
 			BinaryOperatorTypeMismatch(op, left, right) => {
 				let op_str = match op {
@@ -240,9 +694,6 @@ impl Display for ErrorKind<'_> {
 					"Class extends value '{ty}' which is not a constructor function type."
 				)
 			}
-			InvalidNumberLiteral(value) => {
-				write!(f, "Invalid number literal: {value}.")
-			}
 		}
 	}
 }
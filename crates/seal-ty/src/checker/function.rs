@@ -4,11 +4,14 @@ use std::{cell::Cell, ops::Deref};
 use swc_common::Spanned;
 use swc_ecma_ast::{BlockStmt, Function, ReturnStmt, Stmt};
 
-use super::{base::BaseChecker, errors::Error};
+use super::{
+	base::{BaseChecker, Params},
+	errors::Error,
+};
 
 use crate::checker::errors::ErrorKind;
 use crate::sir::{self, Value};
-use crate::{Ty, TyKind, context::TyContext, symbol::Symbol};
+use crate::{Ty, TyKind, context::TyContext};
 
 pub struct FunctionCheckerResult<'tcx> {
 	pub ty: crate::kind::Function<'tcx>,
@@ -19,7 +22,7 @@ pub struct FunctionCheckerResult<'tcx> {
 #[derive(Debug)]
 pub struct FunctionChecker<'tcx> {
 	base: BaseChecker<'tcx>,
-	params: Vec<(Symbol, Ty<'tcx>)>,
+	params: Params<'tcx>,
 	ret: Ty<'tcx>,
 	has_returned: Cell<bool>,
 }
@@ -35,12 +38,12 @@ impl<'tcx> Deref for FunctionChecker<'tcx> {
 impl<'tcx> FunctionChecker<'tcx> {
 	pub fn new(
 		tcx: &'tcx TyContext<'tcx>,
-		params: Vec<(Symbol, Ty<'tcx>)>,
+		params: Params<'tcx>,
 		ret: Ty<'tcx>,
 	) -> FunctionChecker<'tcx> {
 		let base = BaseChecker::new(tcx);
 
-		for (name, ty) in &params {
+		for (name, ty) in params.bindings() {
 			let param = base.add_local(*ty, Value::Param);
 			base.set_binding(name, Some(param), *ty, false);
 		}
@@ -58,11 +61,8 @@ impl<'tcx> FunctionChecker<'tcx> {
 			Some(body) => body,
 			None => {
 				return FunctionCheckerResult {
-					ty: crate::kind::Function::new(
-						// TODO: check_function(self, ..)
-						self.params.clone(),
-						self.ret,
-					),
+					// TODO: check_function(self, ..)
+					ty: self.params.clone().into_function(self.ret),
 					def: sir::Func {
 						locals: HashMap::new(),
 					},
@@ -81,17 +81,15 @@ impl<'tcx> FunctionChecker<'tcx> {
 		for stmt in &body.stmts {
 			self.check_stmt(stmt);
 		}
+		self.finish_overloads();
 
 		if !matches!(self.ret.kind(), TyKind::Void) && !self.has_returned.get() {
 			self.add_error_with_span(ErrorKind::UnexpectedVoid, body.span);
 		}
 
 		FunctionCheckerResult {
-			ty: crate::kind::Function::new(
-				// TODO: check_function(self, ..)
-				self.params.clone(),
-				self.ret,
-			),
+			// TODO: check_function(self, ..)
+			ty: self.params.clone().into_function(self.ret),
 			def: sir::Func {
 				locals: self.base.locals.into_inner(),
 			},
@@ -48,6 +48,8 @@ impl<'tcx> Checker<'tcx> {
 			}
 		};
 
+		self.finish_overloads();
+
 		let errors = self.base.errors.into_inner();
 
 		if errors.is_empty() {
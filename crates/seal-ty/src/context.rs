@@ -11,7 +11,11 @@ use swc_common::SyntaxContext;
 use crate::{
 	Ty, TyKind,
 	intern::interner::Interner,
-	kind::{Array, Class, Function, Interface, Object, Tuple, Union},
+	kind::{
+		Array, BigIntLit, Class, Conditional, Enum, EnumMember, Function, Generic, IndexSignatures,
+		IndexedAccess, Interface, Intersection, Mapped, NumberLit, Object, Overloaded, Signatures,
+		StringMapping, Template, Tuple, TypeParameter, Union,
+	},
 	sir::{Def, DefId},
 	symbol::Symbol,
 };
@@ -68,18 +72,38 @@ impl<'tcx> TyContext<'tcx> {
 		self.new_ty(TyKind::String(Some(value)))
 	}
 
-	pub fn new_const_number(&'tcx self, value: i64) -> Ty<'tcx> {
-		self.new_ty(TyKind::Number(Some(value)))
+	pub fn new_const_number(&'tcx self, value: f64) -> Ty<'tcx> {
+		self.new_ty(TyKind::Number(Some(NumberLit::new(value))))
+	}
+
+	pub fn new_const_bigint(&'tcx self, value: BigIntLit) -> Ty<'tcx> {
+		self.new_ty(TyKind::BigInt(Some(value)))
 	}
 
 	pub fn new_const_boolean(&'tcx self, value: bool) -> Ty<'tcx> {
 		self.new_ty(TyKind::Boolean(Some(value)))
 	}
 
+	pub fn new_enum(&'tcx self, enum_: Rc<Enum<'tcx>>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Enum(enum_))
+	}
+
+	pub fn new_enum_member(&'tcx self, member: EnumMember<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::EnumMember(member))
+	}
+
+	pub fn new_enum_object(&'tcx self, enum_: Rc<Enum<'tcx>>) -> Ty<'tcx> {
+		self.new_ty(TyKind::EnumObject(enum_))
+	}
+
 	pub fn new_function(&'tcx self, function: Function<'tcx>) -> Ty<'tcx> {
 		self.new_ty(TyKind::Function(function))
 	}
 
+	pub fn new_overloaded(&'tcx self, overloaded: Overloaded<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Overloaded(overloaded))
+	}
+
 	pub fn new_class(&'tcx self, class: Class<'tcx>) -> Ty<'tcx> {
 		self.new_ty(TyKind::Class(class))
 	}
@@ -96,6 +120,184 @@ impl<'tcx> TyContext<'tcx> {
 		}
 	}
 
+	/// Creates a normalized intersection: nested intersections are flattened, unions are distributed,
+	/// object and interface members are merged, and disjoint primitives collapse to `never`
+	pub fn new_intersection(&'tcx self, parts: BTreeSet<Ty<'tcx>>) -> Ty<'tcx> {
+		let mut flat = BTreeSet::new();
+
+		for ty in parts {
+			match ty.kind() {
+				TyKind::Intersection(intersection) => flat.extend(intersection.parts()),
+				TyKind::Unknown => {}
+				TyKind::Never | TyKind::Err => return ty,
+				TyKind::Lazy => {
+					unreachable!("Lazy type must be resolved before creating an intersection");
+				}
+				_ => {
+					flat.insert(ty);
+				}
+			}
+		}
+
+		// (A | B) & C => (A & C) | (B & C)
+		if let Some(union) = flat
+			.iter()
+			.find(|ty| matches!(ty.kind(), TyKind::Union(_)))
+			.copied()
+		{
+			flat.remove(&union);
+
+			let TyKind::Union(union) = union.kind() else {
+				unreachable!()
+			};
+
+			return self.new_union(
+				union
+					.arms()
+					.iter()
+					.map(|arm| {
+						let mut parts = flat.clone();
+						parts.insert(*arm);
+						self.new_intersection(parts)
+					})
+					.filter(|ty| !matches!(ty.kind(), TyKind::Never))
+					.collect(),
+			);
+		}
+
+		let mut primitive: Option<Ty<'tcx>> = None;
+		let mut fields: Option<BTreeMap<Atom, Ty<'tcx>>> = None;
+		let mut optional = BTreeSet::new();
+		let mut readonly = BTreeSet::new();
+		let mut index = IndexSignatures::default();
+		let mut signatures = Signatures::default();
+		let mut rest = BTreeSet::new();
+
+		for ty in flat {
+			match ty.kind() {
+				TyKind::Boolean(_)
+				| TyKind::Number(_)
+				| TyKind::BigInt(_)
+				| TyKind::String(_)
+				| TyKind::Null
+				| TyKind::Undefined
+				| TyKind::Void => {
+					primitive = match primitive {
+						None => Some(ty),
+						Some(prev) => match Self::intersect_primitives(prev, ty) {
+							Some(ty) => Some(ty),
+							None => return self.new_ty(TyKind::Never),
+						},
+					};
+				}
+				TyKind::Object(_) | TyKind::Interface(_) => {
+					let (
+						members,
+						optional_members,
+						readonly_members,
+						index_members,
+						signature_members,
+					) = match ty.kind() {
+						TyKind::Object(obj) => (
+							obj.fields(),
+							obj.optional(),
+							obj.readonly(),
+							obj.index(),
+							obj.signatures(),
+						),
+						TyKind::Interface(interface) => (
+							interface.fields(),
+							interface.optional(),
+							interface.readonly(),
+							interface.index(),
+							interface.signatures(),
+						),
+						_ => unreachable!(),
+					};
+
+					index.string = self.intersect_index(index.string, index_members.string);
+					index.number = self.intersect_index(index.number, index_members.number);
+					// the signatures of every part are overloads of the intersection
+					signatures.call.extend(&signature_members.call);
+					signatures.construct.extend(&signature_members.construct);
+					let fields = fields.get_or_insert_with(BTreeMap::new);
+
+					for (key, ty) in members {
+						// a member stays optional (or readonly) only if it is in every part
+						for (modifiers, members) in [
+							(&mut optional, optional_members),
+							(&mut readonly, readonly_members),
+						] {
+							if members.contains(key) {
+								if !fields.contains_key(key) {
+									modifiers.insert(key.clone());
+								}
+							} else {
+								modifiers.remove(key);
+							}
+						}
+
+						let ty = match fields.get(key) {
+							Some(prev) => self.new_intersection(BTreeSet::from([*prev, *ty])),
+							None => *ty,
+						};
+
+						fields.insert(key.clone(), ty);
+					}
+				}
+				_ => {
+					rest.insert(ty);
+				}
+			}
+		}
+
+		rest.extend(primitive);
+		rest.extend(fields.map(|fields| {
+			self.new_object(
+				Object::new(fields)
+					.with_optional(optional)
+					.with_readonly(readonly)
+					.with_index(index)
+					.with_signatures(signatures),
+			)
+		}));
+
+		match rest.len() {
+			0 => self.new_ty(TyKind::Unknown),
+			1 => *rest.first().unwrap(),
+			_ => self.new_ty(TyKind::Intersection(Intersection::new(rest))),
+		}
+	}
+
+	fn intersect_index(
+		&'tcx self,
+		left: Option<Ty<'tcx>>,
+		right: Option<Ty<'tcx>>,
+	) -> Option<Ty<'tcx>> {
+		match (left, right) {
+			(Some(left), Some(right)) => Some(self.new_intersection(BTreeSet::from([left, right]))),
+			(left, right) => left.or(right),
+		}
+	}
+
+	/// `string & "a"` is `"a"`, while `string & number` or `"a" & "b"` have no common value
+	fn intersect_primitives(left: Ty<'tcx>, right: Ty<'tcx>) -> Option<Ty<'tcx>> {
+		use TyKind::*;
+
+		match (left.kind(), right.kind()) {
+			_ if left == right => Some(left),
+			(Boolean(None), Boolean(_))
+			| (Number(None), Number(_))
+			| (BigInt(None), BigInt(_))
+			| (String(None), String(_)) => Some(right),
+			(Boolean(_), Boolean(None))
+			| (Number(_), Number(None))
+			| (BigInt(_), BigInt(None))
+			| (String(_), String(None)) => Some(left),
+			_ => None,
+		}
+	}
+
 	pub fn new_excluded_union(&'tcx self, uni: &Union<'tcx>, arm: Ty<'tcx>) -> Ty<'tcx> {
 		let mut tys = uni.arms().clone();
 		tys.remove(&arm);
@@ -118,12 +320,121 @@ impl<'tcx> TyContext<'tcx> {
 	pub fn new_tuple(&'tcx self, elements: Vec<Ty<'tcx>>) -> Ty<'tcx> {
 		self.new_ty(TyKind::Tuple(Tuple::new(elements)))
 	}
+
+	pub fn new_readonly_array(&'tcx self, element: Ty<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Array(Array::new_readonly(element)))
+	}
+
+	pub fn new_readonly_tuple(&'tcx self, elements: Vec<Ty<'tcx>>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Tuple(Tuple::new_readonly(elements)))
+	}
+
+	pub fn new_type_parameter(&'tcx self, param: TypeParameter<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::TypeParameter(param))
+	}
+
+	pub fn new_generic(&'tcx self, generic: Generic<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Generic(generic))
+	}
+
+	pub fn new_key_of(&'tcx self, ty: Ty<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::KeyOf(ty))
+	}
+
+	pub fn new_indexed_access(&'tcx self, access: IndexedAccess<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::IndexedAccess(access))
+	}
+
+	pub fn new_conditional(&'tcx self, conditional: Conditional<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Conditional(conditional))
+	}
+
+	pub fn new_mapped(&'tcx self, mapped: Mapped<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::Mapped(mapped))
+	}
+
+	/// Creates a template literal type: literal parts are folded into the text, unions are distributed,
+	/// and a template without any non-literal part is a string literal
+	pub fn new_template(&'tcx self, quasis: Vec<Atom>, types: Vec<Ty<'tcx>>) -> Ty<'tcx> {
+		if let Some(position) = types
+			.iter()
+			.position(|ty| matches!(ty.kind(), TyKind::Union(_)))
+		{
+			let TyKind::Union(uni) = types[position].kind() else {
+				unreachable!()
+			};
+
+			return self.new_union(
+				uni.arms()
+					.iter()
+					.map(|arm| {
+						let mut types = types.clone();
+						types[position] = *arm;
+						self.new_template(quasis.clone(), types)
+					})
+					.collect(),
+			);
+		}
+
+		if types.iter().any(|ty| matches!(ty.kind(), TyKind::Never)) {
+			return self.new_ty(TyKind::Never);
+		}
+
+		let mut folded_quasis = vec![quasis[0].to_string()];
+		let mut folded_types = vec![];
+		for (ty, quasi) in types.iter().zip(&quasis[1..]) {
+			let text = match ty.kind() {
+				TyKind::String(Some(value)) => value.to_string(),
+				TyKind::Number(Some(value)) => value.to_string(),
+				TyKind::BigInt(Some(value)) => value.digits().to_string(),
+				TyKind::Boolean(Some(value)) => value.to_string(),
+				TyKind::Null => "null".to_string(),
+				TyKind::Undefined => "undefined".to_string(),
+				// nested templates are spliced in
+				TyKind::Template(template) => {
+					folded_quasis
+						.last_mut()
+						.unwrap()
+						.push_str(&template.quasis[0]);
+					for (ty, inner) in template.types.iter().zip(&template.quasis[1..]) {
+						folded_types.push(*ty);
+						folded_quasis.push(inner.to_string());
+					}
+					folded_quasis.last_mut().unwrap().push_str(quasi);
+					continue;
+				}
+				_ => {
+					folded_types.push(*ty);
+					folded_quasis.push(quasi.to_string());
+					continue;
+				}
+			};
+
+			let last = folded_quasis.last_mut().unwrap();
+			last.push_str(&text);
+			last.push_str(quasi);
+		}
+
+		if folded_types.is_empty() {
+			return self.new_const_string(Atom::new(folded_quasis.remove(0)));
+		}
+
+		self.new_ty(TyKind::Template(Template::new(
+			folded_quasis.into_iter().map(Atom::new).collect(),
+			folded_types,
+		)))
+	}
+
+	pub fn new_string_mapping(&'tcx self, mapping: StringMapping, ty: Ty<'tcx>) -> Ty<'tcx> {
+		self.new_ty(TyKind::StringMapping(mapping, ty))
+	}
 }
 
 #[derive(Debug)]
 pub struct TyConstants<'tcx> {
 	pub boolean: Ty<'tcx>,
 	pub number: Ty<'tcx>,
+	pub bigint: Ty<'tcx>,
 	pub string: Ty<'tcx>,
 	pub err: Ty<'tcx>,
 	pub void: Ty<'tcx>,
@@ -145,6 +456,7 @@ impl<'tcx> TyConstants<'tcx> {
 	pub fn new(tcx: &'tcx TyContext<'tcx>) -> Self {
 		let boolean = tcx.new_ty(TyKind::Boolean(None));
 		let number = tcx.new_ty(TyKind::Number(None));
+		let bigint = tcx.new_ty(TyKind::BigInt(None));
 		let string = tcx.new_ty(TyKind::String(None));
 		let err = tcx.new_ty(TyKind::Err);
 		let void = tcx.new_ty(TyKind::Void);
@@ -173,13 +485,17 @@ impl<'tcx> TyConstants<'tcx> {
 			.collect(),
 		)));
 
+		// parameters after `;` are optional
 		macro_rules! parse_type {
-            (($($param_name:ident: $param_ty:expr),*) => $ret_ty:expr) => {
+            (($($param_name:ident: $param_ty:expr),* $(; $($opt_name:ident: $opt_ty:expr),*)?) => $ret_ty:expr) => {
                 (
                     tcx.new_ty(TyKind::Function(Function::new(
-                        vec![$((Symbol::new((Atom::new(stringify!($param_name)), SyntaxContext::empty())), parse_type!($param_ty))),*],
+                        vec![
+                            $((Symbol::new((Atom::new(stringify!($param_name)), SyntaxContext::empty())), parse_type!($param_ty)),)*
+                            $($((Symbol::new((Atom::new(stringify!($opt_name)), SyntaxContext::empty())), parse_type!($opt_ty)),)*)?
+                        ],
                         parse_type!($ret_ty),
-                    )))
+                    ).with_required(<[&str]>::len(&[$(stringify!($param_name)),*]))))
                 )
             };
             ({ $($prop_name:ident: $prop_ty:expr),* }) => {
@@ -203,6 +519,7 @@ impl<'tcx> TyConstants<'tcx> {
 		Self {
 			boolean,
 			number,
+			bigint,
 			string,
 			err,
 			void,
@@ -215,17 +532,17 @@ impl<'tcx> TyConstants<'tcx> {
 			regexp,
 
 			type_of: tcx.new_union(
-				["boolean", "number", "string"]
+				["bigint", "boolean", "number", "string"]
 					.into_iter()
 					.map(|s| tcx.new_const_string(Atom::new(s)))
 					.collect(),
 			),
 
 			proto_number: [
-				parse_field!(toExponential: parse_type!((fractionDigits: number) => string)),
-				parse_field!(toFixed: parse_type!((digits: number) => string)),
+				parse_field!(toExponential: parse_type!((; fractionDigits: number) => string)),
+				parse_field!(toFixed: parse_type!((; digits: number) => string)),
 				parse_field!(toLocaleString: parse_type!(() => string)),
-				parse_field!(toPrecision: parse_type!((precision: number) => string)),
+				parse_field!(toPrecision: parse_type!((; precision: number) => string)),
 			]
 			.into_iter()
 			.collect(),
@@ -236,26 +553,26 @@ impl<'tcx> TyConstants<'tcx> {
 				parse_field!(charCodeAt: parse_type!((index: number) => number)),
 				parse_field!(codePointAt: parse_type!((index: number) => number)),
 				parse_field!(concat: parse_type!((strings: string) => string)),
-				parse_field!(endsWith: parse_type!((searchString: string) => boolean)),
-				parse_field!(includes: parse_type!((searchString: string) => boolean)),
-				parse_field!(indexOf: parse_type!((searchString: string) => number)),
+				parse_field!(endsWith: parse_type!((searchString: string; endPosition: number) => boolean)),
+				parse_field!(includes: parse_type!((searchString: string; position: number) => boolean)),
+				parse_field!(indexOf: parse_type!((searchString: string; position: number) => number)),
 				parse_field!(isWellFormed: parse_type!(() => boolean)),
-				parse_field!(lastIndexOf: parse_type!((searchString: string) => number)),
+				parse_field!(lastIndexOf: parse_type!((searchString: string; position: number) => number)),
 				parse_field!(localeCompare: parse_type!((compareString: string) => number)),
 				parse_field!(match: parse_type!((regexp: string) => object)),
 				parse_field!(matchAll: parse_type!((regexp: string) => object)),
-				parse_field!(normalize: parse_type!((form: string) => string)),
-				parse_field!(padEnd: parse_type!((targetLength: number, padString: string) => string)),
-				parse_field!(padStart: parse_type!((targetLength: number, padString: string) => string)),
+				parse_field!(normalize: parse_type!((; form: string) => string)),
+				parse_field!(padEnd: parse_type!((targetLength: number; padString: string) => string)),
+				parse_field!(padStart: parse_type!((targetLength: number; padString: string) => string)),
 				parse_field!(repeat: parse_type!((count: number) => string)),
 				parse_field!(replace: parse_type!((searchValue: string, replaceValue: string) => string)),
 				parse_field!(replaceAll: parse_type!((searchValue: string, replaceValue: string) => string)),
 				parse_field!(search: parse_type!((regexp: string) => number)),
-				parse_field!(slice: parse_type!((start: number, end: number) => string)),
-				parse_field!(split: parse_type!((separator: string, limit: number) => object)),
-				parse_field!(startsWith: parse_type!((searchString: string, position: number) => boolean)),
-				parse_field!(substr: parse_type!((start: number, length: number) => string)),
-				parse_field!(substring: parse_type!((start: number, end: number) => string)),
+				parse_field!(slice: parse_type!((; start: number, end: number) => string)),
+				parse_field!(split: parse_type!((separator: string; limit: number) => object)),
+				parse_field!(startsWith: parse_type!((searchString: string; position: number) => boolean)),
+				parse_field!(substr: parse_type!((start: number; length: number) => string)),
+				parse_field!(substring: parse_type!((start: number; end: number) => string)),
 				parse_field!(toLocaleLowerCase: parse_type!(() => string)),
 				parse_field!(toLocaleUpperCase: parse_type!(() => string)),
 				parse_field!(toLowerCase: parse_type!(() => string)),
@@ -52,7 +52,7 @@ impl<T> Eq for Interned<'_, T> {}
 
 impl<T> PartialOrd for Interned<'_, T> {
 	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-		Some(self.1.cmp(&other.1))
+		Some(self.cmp(other))
 	}
 }
 
@@ -2,7 +2,10 @@ use std::{collections::HashMap, fmt::Debug};
 
 use swc_atoms::Atom;
 
-use crate::Ty;
+use crate::{
+	Ty,
+	kind::{BigIntLit, NumberLit},
+};
 
 // TODO: move to a separate module
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -74,7 +77,8 @@ pub enum Value {
 	Err,
 	Ref(DefId),
 	Bool(bool),
-	Int(i64),
+	Number(NumberLit),
+	BigInt(BigIntLit),
 	Str(Atom),
 	Obj(Object),
 	Array(Vec<LocalId>),
@@ -173,12 +177,12 @@ mod tests {
 	#[test]
 	fn test_value_with_data() {
 		let bool_val = Value::Bool(true);
-		let int_val = Value::Int(42);
+		let number_val = Value::Number(NumberLit::new(42.0));
 		let str_val = Value::Str(Atom::new("test"));
 		let obj_val = Value::Obj(Object::new());
 
 		assert_eq!(bool_val, Value::Bool(true));
-		assert_eq!(int_val, Value::Int(42));
+		assert_eq!(number_val, Value::Number(NumberLit::new(42.0)));
 		assert_eq!(str_val, Value::Str(Atom::new("test")));
 		assert_eq!(obj_val, Value::Obj(Object::new()));
 	}
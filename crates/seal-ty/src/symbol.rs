@@ -32,6 +32,11 @@ impl Symbol {
 		Self((Atom::new("@ret"), SyntaxContext::empty()))
 	}
 
+	/// Both the value and the type `this`
+	pub fn new_this() -> Self {
+		Self((Atom::new("this"), SyntaxContext::empty()))
+	}
+
 	pub fn name(&self) -> &Atom {
 		&self.0.0
 	}
@@ -69,6 +74,12 @@ mod tests {
 		assert_eq!(symbol.name(), &Atom::new("@ret"));
 	}
 
+	#[test]
+	fn test_symbol_new_this() {
+		let symbol = Symbol::new_this();
+		assert_eq!(symbol.name(), &Atom::new("this"));
+	}
+
 	#[test]
 	fn test_symbol_name() {
 		let atom = Atom::new("variable");
@@ -27,7 +27,7 @@ impl<'tcx> Ty<'tcx> {
 		Self(interned)
 	}
 
-	pub fn kind(&self) -> &TyKind<'tcx> {
-		&self.0
+	pub fn kind(&self) -> &'tcx TyKind<'tcx> {
+		self.0.0
 	}
 }
@@ -133,3 +133,56 @@ pass!(
         arr satisfies number[];
     "#
 );
+
+// === Readonly Arrays and Tuples ===
+
+pass!(
+	readonly_array_reading,
+	r#"
+        const arr: readonly number[] = [1, 2, 3];
+        arr.length satisfies number;
+        arr.indexOf(2) satisfies number;
+        arr.at(0) satisfies number | undefined;
+        arr satisfies readonly number[];
+    "#
+);
+
+fail!(
+	readonly_array_mutation,
+	r#"
+        const arr: readonly number[] = [1, 2, 3];
+        arr[0] = 4;
+        arr.push(4);
+    "#,
+	&[
+		"Index signature in type 'readonly number[]' only permits reading.",
+		"Property 'push' does not exist on type 'readonly number[]'.",
+		"This expression is not callable.\n  Type '<err>' has no call signatures."
+	]
+);
+
+fail!(
+	readonly_array_not_assignable_to_mutable,
+	r#"
+        const mutable: number[] = [1, 2];
+        mutable.push(3);
+        const frozen: readonly number[] = mutable;
+        const copy: number[] = frozen;
+    "#,
+	&["Type 'readonly number[]' is not assignable to type 'number[]'."]
+);
+
+fail!(
+	readonly_tuple,
+	r#"
+        const pair: readonly [number, string] = [1, "a"];
+        pair.length satisfies 2;
+        pair[0] satisfies number;
+        pair[0] = 2;
+        const copy: [number, string] = pair;
+    "#,
+	&[
+		"Cannot assign to '0' because it is a read-only property.",
+		"Type 'readonly [number, string]' is not assignable to type '[number, string]'."
+	]
+);
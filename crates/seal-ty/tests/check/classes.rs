@@ -9,15 +9,14 @@ pass!(
     "#
 );
 
-fail!(
-	empty_classes_nominally_incompatible,
+pass!(
+	empty_classes_structurally_compatible,
 	r#"
         class A {}
         class B {}
 
         new A() satisfies B;
-    "#,
-	&["Type 'A' is not assignable to type 'B'."]
+    "#
 );
 
 pass!(
@@ -210,3 +209,1181 @@ pass!(
         }
     "#
 );
+
+// === Implements ===
+
+pass!(
+	class_implements,
+	r#"
+        interface Plugin {
+            name: string;
+            run(input: string): number;
+            version?: number;
+        }
+
+        class Counter implements Plugin {
+            name: string = "counter";
+            run(input: string): number {
+                return 1;
+            }
+        }
+
+        new Counter() satisfies Plugin;
+    "#
+);
+
+fail!(
+	class_implements_missing_member,
+	r#"
+        interface Plugin {
+            name: string;
+            run(input: string): number;
+        }
+
+        class Counter implements Plugin {
+            name: string = "counter";
+        }
+    "#,
+	&[
+		"Class 'Counter' incorrectly implements interface 'Plugin'.\n  Property 'run' is missing in type 'Counter' but required in type 'Plugin'."
+	]
+);
+
+fail!(
+	class_implements_incompatible_member,
+	r#"
+        interface Plugin {
+            name: string;
+        }
+
+        class Counter implements Plugin {
+            name: number = 1;
+        }
+    "#,
+	&[
+		"Property 'name' in type 'Counter' is not assignable to the same property in base type 'Plugin'.\n  Type 'number' is not assignable to type 'string'."
+	]
+);
+
+fail!(
+	class_implements_incompatible_method,
+	r#"
+        interface Plugin {
+            run(input: string): number;
+        }
+
+        class Counter implements Plugin {
+            run(input: number): number {
+                return input;
+            }
+        }
+    "#,
+	&[
+		"Property 'run' in type 'Counter' is not assignable to the same property in base type 'Plugin'.\n  Type '(input: number) => number' is not assignable to type '(input: string) => number'."
+	]
+);
+
+pass!(
+	class_implements_inherited_member,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        class Base {
+            name: string = "base";
+        }
+
+        class Derived extends Base implements Named {}
+    "#
+);
+
+pass!(
+	class_implements_multiple,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        interface Aged {
+            age: number;
+        }
+
+        class Person implements Named, Aged {
+            name: string = "Alice";
+            age: number = 30;
+        }
+    "#
+);
+
+fail!(
+	class_implements_generic,
+	r#"
+        interface Box<T> {
+            value: T;
+        }
+
+        class NumberBox implements Box<number> {
+            value: number = 1;
+        }
+
+        class StringBox implements Box<string> {
+            value: number = 1;
+        }
+    "#,
+	&[
+		"Property 'value' in type 'StringBox' is not assignable to the same property in base type 'Box<string>'.\n  Type 'number' is not assignable to type 'string'."
+	]
+);
+
+fail!(
+	class_implements_primitive,
+	r#"
+        type Name = string;
+
+        class Person implements Name {}
+    "#,
+	&[
+		"A class can only implement an object type or intersection of object types with statically known members."
+	]
+);
+
+fail!(
+	class_implements_private_member,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        class Secret implements Named {
+            private name: string = "a";
+        }
+    "#,
+	&[
+		"Class 'Secret' incorrectly implements interface 'Named'.\n  Property 'name' is private in type 'Secret' but not in type 'Named'."
+	]
+);
+
+fail!(
+	class_implements_protected_member,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        class Guarded implements Named {
+            protected name: string = "a";
+        }
+    "#,
+	&[
+		"Class 'Guarded' incorrectly implements interface 'Named'.\n  Property 'name' is protected in type 'Guarded' but public in type 'Named'."
+	]
+);
+
+fail!(
+	class_implements_index_signature,
+	r#"
+        interface Idx {
+            [k: string]: number;
+        }
+
+        class T implements Idx {
+            x: string = "a";
+        }
+    "#,
+	&["Class 'T' incorrectly implements interface 'Idx'."]
+);
+
+pass!(
+	class_with_index_signature_implements,
+	r#"
+        interface Idx {
+            [k: string]: number;
+        }
+
+        class T implements Idx {
+            [k: string]: number;
+            x: number = 1;
+        }
+
+        const t = new T();
+        t.y satisfies number;
+    "#
+);
+
+fail!(
+	class_index_signature_incompatible_member,
+	r#"
+        class T {
+            [k: string]: number;
+            x: string = "a";
+        }
+    "#,
+	&["Property 'x' of type 'string' is not assignable to 'string' index type 'number'."]
+);
+
+fail!(
+	class_implements_call_signature,
+	r#"
+        interface Callable {
+            (x: number): number;
+        }
+
+        class T implements Callable {
+            x: number = 1;
+        }
+    "#,
+	&["Class 'T' incorrectly implements interface 'Callable'."]
+);
+
+// === Member Modifiers ===
+
+pass!(
+	class_public_members,
+	r#"
+        class Account {
+            public id: number = 1;
+            public describe(): string {
+                return "account";
+            }
+        }
+
+        const account = new Account();
+        account.id = 2;
+        account.describe() satisfies string;
+    "#
+);
+
+fail!(
+	class_private_member_access,
+	r#"
+        class Account {
+            private balance: number = 0;
+        }
+
+        new Account().balance;
+    "#,
+	&["Property 'balance' is private and only accessible within class 'Account'."]
+);
+
+fail!(
+	class_private_method_call,
+	r#"
+        class Account {
+            private audit(): void {}
+        }
+
+        new Account().audit();
+    "#,
+	&["Property 'audit' is private and only accessible within class 'Account'."]
+);
+
+fail!(
+	class_private_member_assignment,
+	r#"
+        class Account {
+            private balance: number = 0;
+        }
+
+        const account = new Account();
+        account.balance = 1;
+    "#,
+	&["Property 'balance' is private and only accessible within class 'Account'."]
+);
+
+fail!(
+	class_private_member_in_subclass,
+	r#"
+        class Account {
+            private balance: number = 0;
+        }
+
+        class Savings extends Account {
+            peek(other: Account): number {
+                return other.balance;
+            }
+        }
+    "#,
+	&["Property 'balance' is private and only accessible within class 'Account'."]
+);
+
+fail!(
+	class_protected_member_access,
+	r#"
+        class Account {
+            protected owner: string = "alice";
+        }
+
+        new Account().owner;
+    "#,
+	&[
+		"Property 'owner' is protected and only accessible within class 'Account' and its subclasses."
+	]
+);
+
+pass!(
+	class_protected_member_in_subclass,
+	r#"
+        class Account {
+            protected owner: string = "alice";
+        }
+
+        class Savings extends Account {}
+
+        class Joint extends Savings {
+            peek(other: Account): string {
+                return other.owner;
+            }
+        }
+    "#
+);
+
+fail!(
+	class_private_identifier_access,
+	r#"
+        class Account {
+            #pin: number = 1234;
+        }
+
+        const account = new Account();
+        account.#pin;
+    "#,
+	&[
+		"Property '#pin' is not accessible outside class 'Account' because it has a private identifier."
+	]
+);
+
+fail!(
+	class_readonly_field_assignment,
+	r#"
+        class Account {
+            readonly id: number = 1;
+        }
+
+        const account = new Account();
+        account.id = 2;
+    "#,
+	&["Cannot assign to 'id' because it is a read-only property."]
+);
+
+pass!(
+	class_readonly_field_read,
+	r#"
+        class Account {
+            readonly id: number = 1;
+        }
+
+        new Account().id satisfies number;
+    "#
+);
+
+fail!(
+	class_private_members_nominal,
+	r#"
+        class Account {
+            private balance: number = 0;
+        }
+
+        class Wallet {
+            private balance: number = 0;
+        }
+
+        const account: Account = new Wallet();
+    "#,
+	&["Type 'Wallet' is not assignable to type 'Account'."]
+);
+
+pass!(
+	class_private_members_inherited,
+	r#"
+        class Account {
+            private balance: number = 0;
+        }
+
+        class Savings extends Account {}
+
+        const account: Account = new Savings();
+    "#
+);
+
+fail!(
+	class_private_member_not_public,
+	r#"
+        class Account {
+            private id: number = 0;
+        }
+
+        const account: { id: number } = new Account();
+    "#,
+	&["Type 'Account' is not assignable to type '{id: number}'."]
+);
+
+pass!(
+	class_keyof_public_members,
+	r#"
+        class Holder {
+            value: number = 0;
+            private p: number = 0;
+            protected q: number = 0;
+            #secret: number = 0;
+        }
+
+        const key: keyof Holder = "value";
+    "#
+);
+
+fail!(
+	class_keyof_excludes_private,
+	r##"
+        class Holder {
+            value: number = 0;
+            private p: number = 0;
+            #secret: number = 0;
+        }
+
+        const a: keyof Holder = "p";
+        const b: keyof Holder = "#secret";
+    "##,
+	&[
+		"Type '\"p\"' is not assignable to type '\"value\"'.",
+		"Type '\"#secret\"' is not assignable to type '\"value\"'."
+	]
+);
+
+fail!(
+	class_mapped_type_excludes_private,
+	r#"
+        class Holder {
+            value: number = 0;
+            protected q: number = 0;
+        }
+
+        type Copy<T> = { [K in keyof T]: T[K] };
+
+        const copy: Copy<Holder> = { value: 1, q: 2 };
+    "#,
+	&["Type '{q: number, value: number}' is not assignable to type '{value: number}'."]
+);
+
+// === This ===
+
+pass!(
+	class_this_in_constructor,
+	r#"
+        class Point {
+            x: number;
+            readonly y: number;
+
+            constructor(x: number, y: number) {
+                this.x = x;
+                this.y = y;
+            }
+        }
+    "#
+);
+
+fail!(
+	class_this_field_type_mismatch,
+	r#"
+        class Point {
+            x: number;
+
+            constructor(x: string) {
+                this.x = x;
+            }
+        }
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+fail!(
+	class_this_undeclared_field,
+	r#"
+        class Point {
+            x: number = 0;
+
+            constructor() {
+                this.z = 1;
+            }
+        }
+    "#,
+	&["Property 'z' does not exist on type 'Point'."]
+);
+
+fail!(
+	class_this_readonly_outside_constructor,
+	r#"
+        class Point {
+            readonly x: number = 0;
+
+            reset(): void {
+                this.x = 0;
+            }
+        }
+    "#,
+	&["Cannot assign to 'x' because it is a read-only property."]
+);
+
+pass!(
+	class_this_in_method,
+	r#"
+        class Counter {
+            private count: number = 0;
+
+            increment(): number {
+                this.count = this.count + 1;
+                return this.count;
+            }
+        }
+    "#
+);
+
+fail!(
+	class_this_member_type,
+	r#"
+        class Counter {
+            label: string = "counter";
+
+            count(): number {
+                return this.label;
+            }
+        }
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+pass!(
+	class_this_in_field_initializer,
+	r#"
+        class Rectangle {
+            width: number = 2;
+            height: number = 3;
+            area: number = this.width * this.height;
+        }
+    "#
+);
+
+pass!(
+	class_this_in_arrow,
+	r#"
+        class Counter {
+            count: number = 0;
+
+            adder(): (n: number) => number {
+                return (n: number) => this.count + n;
+            }
+        }
+    "#
+);
+
+pass!(
+	class_polymorphic_this,
+	r#"
+        class Builder {
+            parts: string[] = [];
+
+            add(part: string): this {
+                return this;
+            }
+        }
+
+        class HtmlBuilder extends Builder {
+            tag(name: string): this {
+                return this.add(name);
+            }
+        }
+
+        const builder = new HtmlBuilder().add("a").tag("div").add("b");
+        builder satisfies HtmlBuilder;
+    "#
+);
+
+pass!(
+	class_this_in_unannotated_field_initializer,
+	r#"
+        class Counter {
+            start: number = 1;
+            next = this.start + 1;
+            label = this.describe();
+
+            describe(): string {
+                return "counter";
+            }
+        }
+
+        const counter = new Counter();
+        counter.next satisfies number;
+        counter.label satisfies string;
+    "#
+);
+
+fail!(
+	class_field_used_before_initialization,
+	r#"
+        class K {
+            a = this.b;
+            b = "x";
+            c: string = this.#d;
+            #d: string = "y";
+            e = () => this.f;
+            f = 1;
+        }
+    "#,
+	&[
+		"Property 'b' is used before its initialization.",
+		"Property '#d' is used before its initialization."
+	]
+);
+
+fail!(
+	class_this_outside_class,
+	r#"
+        function f(): void {
+            this;
+        }
+    "#,
+	&["'this' implicitly has type 'any' because it does not have a type annotation."]
+);
+
+fail!(
+	class_this_type_outside_class,
+	r#"
+        let value: this;
+    "#,
+	&["A 'this' type is available only in a non-static member of a class or interface."]
+);
+
+// === Statics ===
+
+pass!(
+	class_static_members,
+	r#"
+        class Counter {
+            static count: number = 0;
+            static label = "counter";
+
+            static increment(): number {
+                this.count = this.count + 1;
+                return this.count;
+            }
+        }
+
+        Counter.count satisfies number;
+        Counter.label satisfies string;
+        Counter.increment() satisfies number;
+        Counter.count = 5;
+    "#
+);
+
+fail!(
+	class_static_member_not_on_instance,
+	r#"
+        class Counter {
+            static count: number = 0;
+        }
+
+        const counter = new Counter();
+        counter.count;
+    "#,
+	&["Property 'count' does not exist on type 'Counter'."]
+);
+
+fail!(
+	class_instance_member_not_static,
+	r#"
+        class Point {
+            x: number = 0;
+        }
+
+        Point.x;
+    "#,
+	&["Property 'x' does not exist on type 'typeof Point'."]
+);
+
+fail!(
+	class_static_readonly,
+	r#"
+        class Config {
+            static readonly version = 1;
+        }
+
+        Config.version = 2;
+    "#,
+	&["Cannot assign to 'version' because it is a read-only property."]
+);
+
+fail!(
+	class_static_private,
+	r#"
+        class Registry {
+            private static entries: string[] = [];
+
+            static add(entry: string): void {
+                this.entries.push(entry);
+            }
+        }
+
+        Registry.entries;
+    "#,
+	&["Property 'entries' is private and only accessible within class 'Registry'."]
+);
+
+pass!(
+	class_static_inherited,
+	r#"
+        class Base {
+            static create(): string {
+                return "base";
+            }
+        }
+
+        class Derived extends Base {}
+
+        Derived.create() satisfies string;
+    "#
+);
+
+pass!(
+	class_static_block,
+	r#"
+        class Cache {
+            static size: number = 0;
+
+            static {
+                this.size = 10;
+            }
+        }
+    "#
+);
+
+fail!(
+	class_static_block_type_error,
+	r#"
+        class Cache {
+            static size: number = 0;
+
+            static {
+                this.size = "large";
+            }
+        }
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+pass!(
+	class_typeof,
+	r#"
+        class Counter {
+            static count: number = 0;
+        }
+
+        const ctor: typeof Counter = Counter;
+        ctor.count satisfies number;
+        new ctor() satisfies Counter;
+    "#
+);
+
+pass!(
+	class_typeof_alias,
+	r#"
+        class Counter {
+            static count: number = 0;
+        }
+
+        type CounterClass = typeof Counter;
+
+        const ctor: CounterClass = Counter;
+        ctor.count satisfies number;
+    "#
+);
+
+pass!(
+	class_satisfies_object_of_statics,
+	r#"
+        class Counter {
+            static count: number = 0;
+        }
+
+        const counted: { count: number } = Counter;
+    "#
+);
+
+fail!(
+	class_typeof_other_class,
+	r#"
+        class A {
+            static a: number = 0;
+        }
+
+        class B {
+            static b: number = 0;
+        }
+
+        const ctor: typeof A = B;
+    "#,
+	&["Type 'typeof B' is not assignable to type 'typeof A'."]
+);
+
+// === Accessors ===
+
+pass!(
+	class_accessors,
+	r#"
+        class Temperature {
+            #celsius: number = 0;
+
+            get celsius(): number {
+                return this.#celsius;
+            }
+
+            set celsius(value: number) {
+                this.#celsius = value;
+            }
+        }
+
+        const temperature = new Temperature();
+        temperature.celsius = 20;
+        temperature.celsius satisfies number;
+    "#
+);
+
+fail!(
+	class_getter_without_setter_readonly,
+	r#"
+        class Circle {
+            radius: number = 1;
+
+            get area(): number {
+                return this.radius * this.radius;
+            }
+        }
+
+        const circle = new Circle();
+        circle.area = 3;
+    "#,
+	&["Cannot assign to 'area' because it is a read-only property."]
+);
+
+fail!(
+	class_setter_param_type,
+	r#"
+        class Label {
+            #text: string = "";
+
+            set text(value: string) {
+                this.#text = value;
+            }
+        }
+
+        const label = new Label();
+        label.text = 1;
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+fail!(
+	class_getter_return_type,
+	r#"
+        class Circle {
+            get radius(): number {
+                return "one";
+            }
+        }
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+pass!(
+	class_static_accessor,
+	r#"
+        class Settings {
+            static #theme: string = "light";
+
+            static get theme(): string {
+                return this.#theme;
+            }
+
+            static set theme(value: string) {
+                this.#theme = value;
+            }
+        }
+
+        Settings.theme = "dark";
+        Settings.theme satisfies string;
+    "#
+);
+
+// === Abstract ===
+
+pass!(
+	abstract_class,
+	r#"
+        abstract class Shape {
+            abstract name: string;
+            abstract area(): number;
+
+            describe(): string {
+                return this.name;
+            }
+        }
+
+        class Square extends Shape {
+            name: string = "square";
+            side: number = 2;
+
+            area(): number {
+                return this.side * this.side;
+            }
+        }
+
+        const shape: Shape = new Square();
+        shape.area() satisfies number;
+        shape.describe() satisfies string;
+    "#
+);
+
+fail!(
+	abstract_class_new,
+	r#"
+        abstract class Shape {
+            abstract area(): number;
+        }
+
+        const shape = new Shape();
+    "#,
+	&["Cannot create an instance of an abstract class."]
+);
+
+fail!(
+	abstract_member_not_implemented,
+	r#"
+        abstract class Shape {
+            abstract area(): number;
+        }
+
+        class Circle extends Shape {}
+    "#,
+	&[
+		"Non-abstract class 'Circle' does not implement inherited abstract member 'area' from class 'Shape'."
+	]
+);
+
+pass!(
+	abstract_class_extends_abstract,
+	r#"
+        abstract class Shape {
+            abstract area(): number;
+            abstract perimeter(): number;
+        }
+
+        abstract class Polygon extends Shape {
+            perimeter(): number {
+                return 0;
+            }
+        }
+
+        class Triangle extends Polygon {
+            area(): number {
+                return 0;
+            }
+        }
+    "#
+);
+
+fail!(
+	abstract_member_not_implemented_through_abstract,
+	r#"
+        abstract class Shape {
+            abstract area(): number;
+        }
+
+        abstract class Polygon extends Shape {}
+
+        class Triangle extends Polygon {}
+    "#,
+	&[
+		"Non-abstract class 'Triangle' does not implement inherited abstract member 'area' from class 'Shape'."
+	]
+);
+
+fail!(
+	abstract_method_in_concrete_class,
+	r#"
+        class Task {
+            abstract run(): void;
+        }
+    "#,
+	&["Abstract methods can only appear within an abstract class."]
+);
+
+fail!(
+	abstract_property_in_concrete_class,
+	r#"
+        class Task {
+            abstract name: string;
+        }
+    "#,
+	&["Abstract properties can only appear within an abstract class."]
+);
+
+fail!(
+	abstract_method_with_body,
+	r#"
+        abstract class Task {
+            abstract run(): void {}
+        }
+    "#,
+	&["Method 'run' cannot have an implementation because it is marked abstract."]
+);
+
+fail!(
+	abstract_property_with_init,
+	r#"
+        abstract class Task {
+            abstract name: string = "task";
+        }
+    "#,
+	&["Property 'name' cannot have an initializer because it is marked abstract."]
+);
+
+fail!(
+	abstract_class_not_constructable,
+	r#"
+        abstract class Shape {}
+
+        const make: { new (): Shape } = Shape;
+    "#,
+	&["Type 'typeof Shape' is not assignable to type '{new (): Shape}'."]
+);
+
+// === Parameter Properties ===
+
+pass!(
+	class_param_props,
+	r#"
+        class User {
+            constructor(public name: string, readonly id: number) {}
+
+            greet(): string {
+                return this.name;
+            }
+        }
+
+        const user = new User("Alice", 1);
+        user.name satisfies string;
+        user.id satisfies number;
+        user.name = "Bob";
+    "#
+);
+
+fail!(
+	class_param_prop_arg_type,
+	r#"
+        class User {
+            constructor(public name: string) {}
+        }
+
+        const user = new User(1);
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+fail!(
+	class_param_prop_readonly,
+	r#"
+        class User {
+            constructor(readonly id: number) {}
+        }
+
+        const user = new User(1);
+        user.id = 2;
+    "#,
+	&["Cannot assign to 'id' because it is a read-only property."]
+);
+
+pass!(
+	class_param_prop_readonly_in_constructor,
+	r#"
+        class User {
+            constructor(readonly id: number) {
+                this.id = id + 1;
+            }
+        }
+    "#
+);
+
+fail!(
+	class_param_prop_private,
+	r#"
+        class Account {
+            constructor(private readonly secret: string) {}
+
+            reveal(): string {
+                return this.secret;
+            }
+        }
+
+        const account = new Account("s");
+        account.secret;
+    "#,
+	&["Property 'secret' is private and only accessible within class 'Account'."]
+);
+
+fail!(
+	class_param_prop_protected,
+	r#"
+        class Person {
+            constructor(protected age: number) {}
+        }
+
+        class Employee extends Person {
+            birthday(): void {
+                this.age = this.age + 1;
+            }
+        }
+
+        function age(employee: Employee): number {
+            return employee.age;
+        }
+    "#,
+	&["Property 'age' is protected and only accessible within class 'Person' and its subclasses."]
+);
+
+pass!(
+	class_param_prop_optional_and_default,
+	r#"
+        class Point {
+            constructor(public x: number = 0, public label?: string) {}
+        }
+
+        const point = new Point();
+        point.x satisfies number;
+        point.label satisfies string | undefined;
+    "#
+);
+
+pass!(
+	class_param_prop_implements,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        class Person implements Named {
+            constructor(public name: string) {}
+        }
+    "#
+);
+
+pass!(
+	class_param_prop_implements_abstract,
+	r#"
+        abstract class Entity {
+            abstract id: number;
+        }
+
+        class Row extends Entity {
+            constructor(public id: number) {}
+        }
+    "#
+);
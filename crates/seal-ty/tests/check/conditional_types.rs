@@ -0,0 +1,111 @@
+use super::{fail, pass};
+
+pass!(
+	conditional_type,
+	r#"
+        type IsString<T> = T extends string ? true : false;
+
+        const a: IsString<"hello"> = true;
+        const b: IsString<42> = false;
+        const c: string extends string ? 1 : 2 = 1;
+    "#
+);
+
+fail!(
+	conditional_type_false_branch,
+	r#"
+        type IsString<T> = T extends string ? true : false;
+
+        const a: IsString<42> = true;
+    "#,
+	&["Type 'true' is not assignable to type 'false'."]
+);
+
+pass!(
+	conditional_type_distributes_over_union,
+	r#"
+        type Kind<T> = T extends string ? "text" : "other";
+
+        const a: Kind<string | number> = "text";
+        const b: Kind<string | number> = "other";
+    "#
+);
+
+fail!(
+	conditional_type_without_distribution,
+	r#"
+        type Kind<T> = [T] extends [string] ? "text" : "other";
+
+        const a: Kind<string | number> = "text";
+    "#,
+	&["Type '\"text\"' is not assignable to type '\"other\"'."]
+);
+
+fail!(
+	non_nullable,
+	r#"
+        type NonNullable<T> = T extends null | undefined ? never : T;
+
+        const a: NonNullable<string | null | undefined> = "value";
+        const b: NonNullable<string | null> = null;
+    "#,
+	&["Type 'null' is not assignable to type 'string'."]
+);
+
+fail!(
+	infer_return_type,
+	r#"
+        type ReturnType<T> = T extends () => infer R ? R : never;
+
+        const a: ReturnType<() => number> = 1;
+        const b: ReturnType<() => number> = "one";
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+pass!(
+	infer_from_object_and_array,
+	r#"
+        type Unwrap<T> = T extends { value: infer V } ? V : T;
+        type ElementOf<T> = T extends (infer E)[] ? E : never;
+
+        const a: Unwrap<{ value: string }> = "text";
+        const b: Unwrap<number> = 1;
+        const c: ElementOf<boolean[]> = true;
+    "#
+);
+
+fail!(
+	conditional_type_in_generic_function,
+	r#"
+        type Boxed<T> = T extends string ? { text: T } : { other: T };
+
+        function box<T>(value: T, boxed: Boxed<T>): Boxed<T> {
+            return boxed;
+        }
+
+        box("a", { text: "a" }).text satisfies string;
+        box(1, { text: 1 });
+    "#,
+	&["Type '{text: 1}' is not assignable to type '{other: 1}'."]
+);
+
+fail!(
+	conditional_type_with_generic_extends,
+	r#"
+        type IsStr<T> = string extends T ? true : false;
+
+        const a: IsStr<string> = true;
+        const b: IsStr<string | number> = true;
+        const c: IsStr<number> = true;
+    "#,
+	&["Type 'true' is not assignable to type 'false'."]
+);
+
+fail!(
+	infer_outside_conditional,
+	r#"
+        type X = infer U;
+    "#,
+	&["'infer' declarations are only permitted in the 'extends' clause of a conditional type."]
+);
@@ -154,3 +154,16 @@ fail!(
 		"This comparison appears to be unintentional because the types 'number' and '\"hello\"' have no overlap."
 	]
 );
+
+pass!(
+	typeof_bigint_narrowing,
+	r#"
+        let amount: bigint | number = 100n;
+
+        if (typeof amount === 'bigint') {
+            amount satisfies bigint;
+        } else {
+            amount satisfies number;
+        }
+    "#
+);
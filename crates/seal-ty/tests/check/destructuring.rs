@@ -0,0 +1,222 @@
+use super::{fail, pass};
+
+// === Object Patterns ===
+
+pass!(
+	object_pattern,
+	r#"
+        const obj = { a: 1, b: "x", c: true };
+        const { a, b: renamed } = obj;
+
+        a satisfies number;
+        renamed satisfies string;
+    "#
+);
+
+pass!(
+	object_pattern_rest,
+	r#"
+        const obj = { a: 1, b: "x", c: true };
+        const { a, ...others } = obj;
+
+        others satisfies { b: string; c: boolean };
+    "#
+);
+
+pass!(
+	nested_object_pattern,
+	r#"
+        const { nested: { deep } } = { nested: { deep: 1 } };
+
+        deep satisfies number;
+    "#
+);
+
+fail!(
+	object_pattern_missing_property,
+	r#"
+        const obj = { a: 1 };
+        const { b } = obj;
+    "#,
+	&["Property 'b' does not exist on type '{a: 1}'."]
+);
+
+fail!(
+	object_rest_from_non_object,
+	r#"
+        const { ...rest } = 1;
+    "#,
+	&["Rest types may only be created from object types."]
+);
+
+// === Defaults ===
+
+pass!(
+	pattern_default_removes_undefined,
+	r#"
+        const obj: { d?: number } = {};
+        const { d = 5 } = obj;
+
+        d satisfies number;
+    "#
+);
+
+fail!(
+	pattern_default_wrong_type,
+	r#"
+        const obj: { d?: number } = {};
+        const { d = "s" } = obj;
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+// === Array Patterns ===
+
+pass!(
+	array_pattern,
+	r#"
+        const [x, , y, ...zs] = [1, 2, 3, 4];
+
+        x satisfies number;
+        y satisfies number;
+        zs satisfies number[];
+    "#
+);
+
+pass!(
+	tuple_pattern,
+	r#"
+        const t: [number, string, boolean] = [1, "a", true];
+        const [p, ...rest] = t;
+
+        p satisfies number;
+        rest satisfies [string, boolean];
+    "#
+);
+
+fail!(
+	tuple_pattern_out_of_bounds,
+	r#"
+        const t: [number, string] = [1, "a"];
+        const [p, q, r] = t;
+    "#,
+	&["Tuple type '[number, string]' of length '2' has no element at index '2'."]
+);
+
+fail!(
+	array_pattern_non_array,
+	r#"
+        const [n] = 5;
+    "#,
+	&["Type '5' is not an array type."]
+);
+
+// === Declarations ===
+
+pass!(
+	let_pattern_is_widened,
+	r#"
+        let { a } = { a: 1 };
+        a = 2;
+    "#
+);
+
+fail!(
+	annotated_pattern,
+	r#"
+        const { a }: { a: string } = { a: 1 };
+    "#,
+	&["Type '{a: number}' is not assignable to type '{a: string}'."]
+);
+
+fail!(
+	pattern_const_reassign,
+	r#"
+        const { a } = { a: 1 };
+        a = 2;
+    "#,
+	&[
+		"Cannot assign to 'a' because it is a constant.",
+		"Type '2' is not assignable to type '1'."
+	]
+);
+
+// === Parameters ===
+
+pass!(
+	destructured_params,
+	r#"
+        function f({ a, b }: { a: number; b: string }, [c]: [boolean]): string {
+            return b;
+        }
+
+        const t: [boolean] = [true];
+        f({ a: 1, b: "x" }, t);
+        f satisfies (p: { a: number; b: string }, q: [boolean]) => string;
+    "#
+);
+
+pass!(
+	destructured_arrow_param,
+	r#"
+        const g = ({ a }: { a: number }) => a;
+
+        g({ a: 1 }) satisfies number;
+    "#
+);
+
+fail!(
+	destructured_param_missing_property,
+	r#"
+        function f({ c }: { a: number }): void {}
+    "#,
+	&["Property 'c' does not exist on type '{a: number}'."]
+);
+
+fail!(
+	destructured_param_missing_type_ann,
+	r#"
+        function f({ a }): void {}
+    "#,
+	&["Parameter must have a type annotation."]
+);
+
+// === Assignments ===
+
+pass!(
+	swap_by_array_pattern,
+	r#"
+        let m = 1;
+        let k = 2;
+        [m, k] = [k, m];
+    "#
+);
+
+pass!(
+	assign_by_object_pattern,
+	r#"
+        let m = 1;
+        ({ a: m } = { a: 2 });
+    "#
+);
+
+fail!(
+	assign_pattern_to_const,
+	r#"
+        const c = 1;
+        [c] = [2];
+    "#,
+	&[
+		"Cannot assign to 'c' because it is a constant.",
+		"Type 'number' is not assignable to type '1'."
+	]
+);
+
+fail!(
+	assign_pattern_wrong_type,
+	r#"
+        let s = "a";
+        ({ a: s } = { a: 2 });
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
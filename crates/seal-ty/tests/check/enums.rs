@@ -0,0 +1,217 @@
+use super::{fail, pass};
+
+// === Numeric Enums ===
+
+pass!(
+	numeric_enum_auto_increment,
+	r#"
+        enum Direction { Up, Down, Left = 10, Right }
+
+        Direction.Up satisfies 0;
+        Direction.Down satisfies 1;
+        Direction.Left satisfies 10;
+        Direction.Right satisfies 11;
+        Direction.Up satisfies Direction;
+        Direction.Up satisfies number;
+    "#
+);
+
+pass!(
+	numeric_enum_accepts_numbers,
+	r#"
+        enum Priority { Low = 1, High = 2 }
+
+        const low: Priority = 1;
+        const level: number = Priority.High;
+        const name: string = Priority[1];
+    "#
+);
+
+fail!(
+	numeric_enum_rejects_unknown_value,
+	r#"
+        enum Priority { Low = 1, High = 2 }
+
+        const p: Priority = 3;
+    "#,
+	&["Type '3' is not assignable to type 'Priority'."]
+);
+
+pass!(
+	enum_constant_initializers,
+	r#"
+        enum Flags { None = 0, Read = 1 << 0, Write = 1 << 1, ReadWrite = Read | Write, Mask = ~0 }
+        enum Derived { First = Flags.Write * 10, Second }
+
+        Flags.ReadWrite satisfies 3;
+        Flags.Mask satisfies -1;
+        Derived.First satisfies 20;
+        Derived.Second satisfies 21;
+    "#
+);
+
+// === String Enums ===
+
+pass!(
+	string_enum_members,
+	r#"
+        enum Currency { Euro = "EUR", Dollar = "USD", Pound = `GB${"P"}` }
+
+        Currency.Euro satisfies "EUR";
+        Currency.Pound satisfies "GBP";
+        const code: string = Currency.Dollar;
+    "#
+);
+
+fail!(
+	string_enum_is_nominal,
+	r#"
+        enum Currency { Euro = "EUR", Dollar = "USD" }
+
+        const c: Currency = "EUR";
+    "#,
+	&["Type '\"EUR\"' is not assignable to type 'Currency'."]
+);
+
+fail!(
+	string_enum_member_requires_initializer,
+	r#"
+        enum Currency { Euro = "EUR", Dollar }
+    "#,
+	&["Enum member must have initializer."]
+);
+
+// === Enum Types ===
+
+fail!(
+	enums_are_not_assignable_to_each_other,
+	r#"
+        enum Color { Red, Green }
+        enum Fruit { Apple, Banana }
+
+        let color: Color = Color.Red;
+        color = Fruit.Apple;
+    "#,
+	&["Type 'Fruit.Apple' is not assignable to type 'Color'."]
+);
+
+fail!(
+	enum_members_in_unions,
+	r#"
+        enum Status { Active, Paused, Closed }
+        type Open = Status.Active | Status.Paused;
+
+        const a: Open = Status.Active;
+        const b: Open = Status.Closed;
+        const all: Status.Active | Status.Paused | Status.Closed = a;
+    "#,
+	&["Type 'Status.Closed' is not assignable to type 'Status.Active | Status.Paused'."]
+);
+
+fail!(
+	enum_member_types_by_name,
+	r#"
+        enum Color { Red }
+
+        type Blue = Color.Blue;
+        type Nested = Color.Red.x;
+        type Missing = A.B.C;
+    "#,
+	&[
+		"Namespace 'Color' has no exported member 'Blue'.",
+		"Namespace 'Color.Red' has no exported member 'x'.",
+		"Cannot find name 'A'.",
+	]
+);
+
+fail!(
+	enum_object_members,
+	r#"
+        enum Color { Red, Green }
+
+        type Key = keyof typeof Color;
+        const key: Key = "Green";
+
+        Color.Red = 1;
+        const purple = Color.Purple;
+    "#,
+	&[
+		"Cannot assign to 'Red' because it is a read-only property.",
+		"Property 'Purple' does not exist on type 'typeof Color'.",
+	]
+);
+
+pass!(
+	let_enum_member_widens_to_enum,
+	r#"
+        enum Color { Red, Green }
+
+        let color = Color.Red;
+        color = Color.Green;
+        color satisfies Color;
+    "#
+);
+
+// === Switch Narrowing ===
+
+fail!(
+	enum_switch_narrowing,
+	r#"
+        enum Status { Active, Paused, Closed }
+
+        let status: Status = Status.Active;
+        switch (status) {
+            case Status.Active:
+                status satisfies Status.Active;
+                break;
+            case Status.Paused:
+            case Status.Closed:
+                status satisfies Status.Paused | Status.Closed;
+                break;
+            default:
+                const unreachable: never = status;
+        }
+        switch (status) {
+            case Status.Active:
+                break;
+            default:
+                const missing: never = status;
+        }
+    "#,
+	&["Type 'Status.Paused | Status.Closed' is not assignable to type 'never'."]
+);
+
+// === Const Enums ===
+
+pass!(
+	const_enum_member_access,
+	r#"
+        const enum Size { Small = 1, Large = Small * 4 }
+
+        const large = Size.Large;
+        large satisfies 4;
+        large satisfies Size;
+        type Sizes = typeof Size;
+    "#
+);
+
+fail!(
+	const_enum_used_as_value,
+	r#"
+        const enum Size { Small, Large }
+
+        const sizes = Size;
+    "#,
+	&[
+		"'const' enums can only be used in property or index access expressions or the right hand side of an import declaration or export assignment or type query."
+	]
+);
+
+fail!(
+	const_enum_requires_constant_initializers,
+	r#"
+        const values = [1, 2];
+        const enum Size { Small = values.length }
+    "#,
+	&["const enum member initializers must be constant expressions."]
+);
@@ -184,7 +184,7 @@ pass!(
         }
 
         f satisfies () => number;
-        f(42) satisfies number;
+        f() satisfies number;
     "#
 );
 
@@ -0,0 +1,368 @@
+use super::{fail, pass};
+
+pass!(
+	generic_function_identity,
+	r#"
+        function id<T>(x: T): T {
+            return x;
+        }
+
+        id(42) satisfies 42;
+        id("hello") satisfies "hello";
+    "#
+);
+
+pass!(
+	generic_function_type_param_in_body,
+	r#"
+        function id<T>(x: T): T {
+            const y: T = x;
+            return y;
+        }
+
+        id(true) satisfies boolean;
+    "#
+);
+
+pass!(
+	generic_function_explicit_type_args,
+	r#"
+        function id<T>(x: T): T {
+            return x;
+        }
+
+        const n = id<number>(42);
+        n satisfies number;
+    "#
+);
+
+fail!(
+	generic_function_explicit_type_args_mismatch,
+	r#"
+        function id<T>(x: T): T {
+            return x;
+        }
+
+        id<string>(42);
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+fail!(
+	generic_function_wrong_num_type_args,
+	r#"
+        function id<T>(x: T): T {
+            return x;
+        }
+
+        id<string, number>("hello");
+    "#,
+	&["Expected 1 type arguments, but got 2."]
+);
+
+fail!(
+	non_generic_function_with_type_args,
+	r#"
+        function f(n: number): number {
+            return n;
+        }
+
+        f<number>(42);
+    "#,
+	&["Expected 0 type arguments, but got 1."]
+);
+
+pass!(
+	generic_function_infer_from_array,
+	r#"
+        function first<T>(xs: T[]): T {
+            return xs[0];
+        }
+
+        const n = first([1, 2, 3]);
+        n satisfies number;
+    "#
+);
+
+pass!(
+	generic_function_multiple_type_params,
+	r#"
+        function second<A, B>(a: A, b: B): B {
+            return b;
+        }
+
+        second(1, "hello") satisfies "hello";
+    "#
+);
+
+pass!(
+	generic_function_common_supertype,
+	r#"
+        function pick<T>(a: T, b: T): T {
+            return a;
+        }
+
+        pick(1, 2) satisfies number;
+    "#
+);
+
+fail!(
+	generic_function_conflicting_candidates,
+	r#"
+        function pick<T>(a: T, b: T): T {
+            return a;
+        }
+
+        pick(1, "hello");
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+pass!(
+	generic_function_infer_through_callback,
+	r#"
+        function apply<T, R>(x: T, f: (x: T) => R): R {
+            return f(x);
+        }
+
+        apply(42, (x: number): string => "hello") satisfies string;
+    "#
+);
+
+pass!(
+	generic_function_infer_from_union_param,
+	r#"
+        function orElse<T>(x: T | undefined, fallback: T): T {
+            return fallback;
+        }
+
+        let n: number | undefined = 42;
+        orElse(n, 0) satisfies number;
+    "#
+);
+
+pass!(
+	generic_arrow_function,
+	r#"
+        const id = <T,>(x: T): T => x;
+
+        id(42) satisfies 42;
+        id<string>("hello") satisfies string;
+    "#
+);
+
+pass!(
+	generic_function_satisfies_generic_signature,
+	r#"
+        function id<T>(x: T): T {
+            return x;
+        }
+
+        id satisfies <U>(x: U) => U;
+    "#
+);
+
+pass!(
+	generic_function_assignable_to_instantiated_signature,
+	r#"
+        function id<T>(x: T): T {
+            return x;
+        }
+
+        const f: (x: number) => number = id;
+    "#
+);
+
+fail!(
+	generic_function_not_assignable_to_incompatible_signature,
+	r#"
+        function wrap<T>(x: T): T[] {
+            return [x];
+        }
+
+        const f: (x: number) => string = wrap;
+    "#,
+	&["Type '<T>(x: T) => T[]' is not assignable to type '(x: number) => string'."]
+);
+
+pass!(
+	generic_function_constraint,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        function getName<T extends Named>(x: T): string {
+            return x.name;
+        }
+
+        getName({ name: "Alice" }) satisfies string;
+    "#
+);
+
+fail!(
+	generic_function_constraint_violation,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        function getName<T extends Named>(x: T): string {
+            return x.name;
+        }
+
+        getName(42);
+        getName<number>(42);
+    "#,
+	&[
+		"Type 'number' is not assignable to type 'Named'.",
+		"Type 'number' does not satisfy the constraint 'Named'."
+	]
+);
+
+pass!(
+	generic_interface,
+	r#"
+        interface Box<T> {
+            value: T;
+        }
+
+        const b: Box<number> = { value: 42 };
+        b.value satisfies number;
+    "#
+);
+
+fail!(
+	generic_interface_mismatch,
+	r#"
+        interface Box<T> {
+            value: T;
+        }
+
+        const b: Box<string> = { value: 42 };
+    "#,
+	&["Type '{value: number}' is not assignable to type 'Box<string>'."]
+);
+
+fail!(
+	generic_interface_instantiations_incompatible,
+	r#"
+        interface Box<T> {
+            value: T;
+        }
+
+        let a: Box<number> = { value: 42 };
+        a satisfies Box<string>;
+    "#,
+	&["Type 'Box<number>' is not assignable to type 'Box<string>'."]
+);
+
+pass!(
+	generic_type_alias_with_default,
+	r#"
+        type Pair<A, B = A> = [A, B];
+
+        const p: Pair<number> = [1, 2] as const;
+        const q: Pair<number, string> = [1, "hello"] as const;
+    "#
+);
+
+fail!(
+	generic_type_alias_wrong_num_type_args,
+	r#"
+        type Pair<A, B = A> = [A, B];
+        type Box<T> = { value: T };
+
+        let p: Pair;
+        let q: Pair<number, string, boolean>;
+        let b: Box;
+    "#,
+	&[
+		"Generic type 'Pair<A, B>' requires between 1 and 2 type arguments.",
+		"Generic type 'Pair<A, B>' requires between 1 and 2 type arguments.",
+		"Generic type 'Box<T>' requires 1 type argument(s)."
+	]
+);
+
+fail!(
+	generic_type_alias_constraint_violation,
+	r#"
+        type Keyed<K extends string> = { key: K };
+
+        let a: Keyed<"id">;
+        let b: Keyed<number>;
+    "#,
+	&["Type 'number' does not satisfy the constraint 'string'."]
+);
+
+fail!(
+	generic_default_violates_constraint,
+	r#"
+        type Keyed<K extends string = number> = { key: K };
+    "#,
+	&["Type 'number' does not satisfy the constraint 'string'."]
+);
+
+fail!(
+	type_args_on_non_generic_type,
+	r#"
+        interface Point {
+            x: number;
+        }
+
+        let p: Point<number>;
+    "#,
+	&["Type 'Point' is not generic."]
+);
+
+pass!(
+	generic_class,
+	r#"
+        class Stack<T> {
+            items: T[] = [];
+
+            constructor(first: T) {
+            }
+
+            push(item: T): void {
+            }
+        }
+
+        new Stack(1) satisfies Stack<number>;
+        const s: Stack<string> = new Stack<string>("a");
+        s.push("b");
+        s.items satisfies string[];
+    "#
+);
+
+fail!(
+	generic_class_mismatch,
+	r#"
+        class Stack<T> {
+            items: T[] = [];
+
+            constructor(first: T) {
+            }
+        }
+
+        const s: Stack<number> = new Stack("a");
+        new Stack<string>(1);
+    "#,
+	&[
+		"Type 'Stack<string>' is not assignable to type 'Stack<number>'.",
+		"Type 'number' is not assignable to type 'string'."
+	]
+);
+
+pass!(
+	generic_class_extends_instantiated,
+	r#"
+        class Box<T> {
+            values: T[] = [];
+        }
+
+        class NumberBox extends Box<number> {}
+
+        new NumberBox().values satisfies number[];
+    "#
+);
@@ -0,0 +1,403 @@
+use super::{fail, pass};
+
+// === Extends ===
+
+pass!(
+	interface_extends,
+	r#"
+        interface Animal {
+            name: string;
+        }
+
+        interface Dog extends Animal {
+            breed: string;
+        }
+
+        const dog: Dog = { name: "Rex", breed: "Husky" };
+        dog.name satisfies string;
+        dog satisfies Animal;
+    "#
+);
+
+fail!(
+	interface_extends_missing_inherited,
+	r#"
+        interface Animal {
+            name: string;
+        }
+
+        interface Dog extends Animal {
+            breed: string;
+        }
+
+        const dog: Dog = { breed: "Husky" };
+    "#,
+	&["Type '{breed: \"Husky\"}' is not assignable to type 'Dog'."]
+);
+
+pass!(
+	interface_extends_transitively,
+	r#"
+        interface A {
+            a: number;
+        }
+
+        interface B extends A {
+            b: number;
+        }
+
+        interface C extends B {
+            c: number;
+        }
+
+        const c: C = { a: 1, b: 2, c: 3 };
+        c satisfies A;
+        c.a satisfies number;
+    "#
+);
+
+pass!(
+	interface_extends_narrower_property,
+	r#"
+        interface Shape {
+            kind: string;
+        }
+
+        interface Circle extends Shape {
+            kind: "circle";
+            radius: number;
+        }
+
+        const circle: Circle = { kind: "circle", radius: 1 };
+        circle satisfies Shape;
+    "#
+);
+
+fail!(
+	interface_incorrectly_extends,
+	r#"
+        interface Shape {
+            kind: string;
+        }
+
+        interface Circle extends Shape {
+            kind: number;
+        }
+    "#,
+	&[
+		"Interface 'Circle' incorrectly extends interface 'Shape'.\n  Types of property 'kind' are incompatible."
+	]
+);
+
+pass!(
+	interface_extends_type_alias,
+	r#"
+        type Point = { x: number; y: number };
+
+        interface Point3 extends Point {
+            z: number;
+        }
+
+        const p: Point3 = { x: 1, y: 2, z: 3 };
+        p.x satisfies number;
+    "#
+);
+
+fail!(
+	interface_extends_primitive,
+	r#"
+        type Name = string;
+
+        interface Named extends Name {}
+    "#,
+	&[
+		"An interface can only extend an object type or intersection of object types with statically known members."
+	]
+);
+
+pass!(
+	interface_extends_generic,
+	r#"
+        interface Box<T> {
+            value: T;
+        }
+
+        interface NumberBox extends Box<number> {
+            label: string;
+        }
+
+        const box: NumberBox = { value: 1, label: "one" };
+        box.value satisfies number;
+        box satisfies Box<number>;
+    "#
+);
+
+pass!(
+	generic_interface_extends_generic,
+	r#"
+        interface Box<T> {
+            value: T;
+        }
+
+        interface LabeledBox<T> extends Box<T> {
+            label: string;
+        }
+
+        const box: LabeledBox<string> = { value: "a", label: "b" };
+        box.value satisfies string;
+    "#
+);
+
+pass!(
+	interface_inherits_methods,
+	r#"
+        interface Reader {
+            read(): string;
+        }
+
+        interface Stream extends Reader {
+            close(): void;
+        }
+
+        function use(stream: Stream): string {
+            return stream.read();
+        }
+    "#
+);
+
+// === Multiple Inheritance ===
+
+pass!(
+	interface_extends_multiple,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        interface Aged {
+            age: number;
+        }
+
+        interface Person extends Named, Aged {}
+
+        const person: Person = { name: "Alice", age: 30 };
+        person satisfies Named;
+        person satisfies Aged;
+    "#
+);
+
+pass!(
+	interface_extends_multiple_same_property,
+	r#"
+        interface A {
+            id: number;
+        }
+
+        interface B {
+            id: number;
+        }
+
+        interface C extends A, B {}
+
+        const c: C = { id: 1 };
+    "#
+);
+
+fail!(
+	interface_bases_conflict,
+	r#"
+        interface A {
+            id: number;
+        }
+
+        interface B {
+            id: string;
+        }
+
+        interface C extends A, B {}
+    "#,
+	&[
+		"Interface 'C' cannot simultaneously extend types 'A' and 'B'.\n  Named property 'id' of types 'A' and 'B' are not identical."
+	]
+);
+
+pass!(
+	interface_redeclares_conflicting_property,
+	r#"
+        interface A {
+            id: string | number;
+        }
+
+        interface B {
+            id: string;
+        }
+
+        interface C extends A, B {
+            id: "a";
+        }
+    "#
+);
+
+// === Declaration Merging ===
+
+pass!(
+	interface_merging,
+	r#"
+        interface Config {
+            host: string;
+        }
+
+        interface Config {
+            port: number;
+        }
+
+        const config: Config = { host: "localhost", port: 80 };
+        config.port satisfies number;
+    "#
+);
+
+fail!(
+	interface_merging_missing_member,
+	r#"
+        interface Config {
+            host: string;
+        }
+
+        interface Config {
+            port: number;
+        }
+
+        const config: Config = { host: "localhost" };
+    "#,
+	&["Type '{host: \"localhost\"}' is not assignable to type 'Config'."]
+);
+
+fail!(
+	interface_merging_conflicting_property,
+	r#"
+        interface Config {
+            port: number;
+        }
+
+        interface Config {
+            port: string;
+        }
+    "#,
+	&[
+		"Subsequent property declarations must have the same type.  Property 'port' must be of type 'number', but here has type 'string'."
+	]
+);
+
+pass!(
+	interface_merging_same_property,
+	r#"
+        interface Config {
+            port: number;
+        }
+
+        interface Config {
+            port: number;
+        }
+
+        const config: Config = { port: 80 };
+    "#
+);
+
+pass!(
+	interface_merging_methods_overload,
+	r#"
+        interface Parser {
+            parse(input: string): number;
+        }
+
+        interface Parser {
+            parse(input: number): string;
+        }
+
+        function use(parser: Parser): void {
+            parser.parse("1") satisfies number;
+            parser.parse(1) satisfies string;
+        }
+    "#
+);
+
+pass!(
+	interface_merging_keeps_bases,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        interface User extends Named {
+            id: number;
+        }
+
+        interface User {
+            email: string;
+        }
+
+        const user: User = { name: "a", id: 1, email: "b" };
+        user satisfies Named;
+    "#
+);
+
+pass!(
+	generic_interface_merging,
+	r#"
+        interface Pair<T> {
+            first: T;
+        }
+
+        interface Pair<T> {
+            second: T;
+        }
+
+        const pair: Pair<number> = { first: 1, second: 2 };
+        pair.second satisfies number;
+    "#
+);
+
+fail!(
+	generic_interface_merging_type_params_differ,
+	r#"
+        interface Pair<T> {
+            first: T;
+        }
+
+        interface Pair<T, U> {
+            second: U;
+        }
+    "#,
+	&["All declarations of 'Pair' must have identical type parameters."]
+);
+
+// === Assignability ===
+
+pass!(
+	empty_interfaces_structurally_compatible,
+	r#"
+        interface A {}
+        interface B {}
+
+        const a: A = {};
+        const b: B = a;
+    "#
+);
+
+fail!(
+	unrelated_interfaces_incompatible,
+	r#"
+        interface A {
+            a: number;
+        }
+
+        interface B {
+            b: number;
+        }
+
+        function convert(a: A): B {
+            return a;
+        }
+    "#,
+	&["Type 'A' is not assignable to type 'B'."]
+);
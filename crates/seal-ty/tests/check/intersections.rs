@@ -0,0 +1,114 @@
+use super::{fail, pass};
+
+pass!(
+	intersection_of_interfaces,
+	r#"
+        interface A {
+            a: number;
+        }
+        interface B {
+            b: string;
+        }
+
+        const ab: A & B = { a: 1, b: "x" };
+        ab.a satisfies number;
+        ab.b satisfies string;
+        ab satisfies A;
+        ab satisfies B;
+    "#
+);
+
+fail!(
+	intersection_missing_member,
+	r#"
+        interface A {
+            a: number;
+        }
+        interface B {
+            b: string;
+        }
+
+        const ab: A & B = { a: 1 };
+    "#,
+	&["Type '{a: number}' is not assignable to type '{a: number, b: string}'."]
+);
+
+pass!(
+	intersection_conflicting_members,
+	r#"
+        type C = { a: string } & { a: number };
+
+        function f(c: C) {
+            c.a satisfies never;
+        }
+    "#
+);
+
+pass!(
+	intersection_of_disjoint_primitives_is_never,
+	r#"
+        function f(x: string & number, y: "a" & "b") {
+            x satisfies never;
+            y satisfies never;
+        }
+
+        let lit: string & "a" = "a";
+    "#
+);
+
+pass!(
+	intersection_distributes_over_union,
+	r#"
+        interface A {
+            a: number;
+        }
+        interface B {
+            b: string;
+        }
+
+        type X = (A | B) & { c: boolean };
+
+        const x: X = { a: 1, c: true };
+        const y: X = { b: "y", c: false };
+    "#
+);
+
+fail!(
+	branded_id,
+	r#"
+        type UserId = string & { __brand: "UserId" };
+
+        function load(id: UserId): string {
+            id satisfies string;
+            return id.toUpperCase();
+        }
+
+        load("x");
+    "#,
+	&["Type 'string' is not assignable to type 'string & {__brand: \"UserId\"}'."]
+);
+
+pass!(
+	intersection_with_type_parameter,
+	r#"
+        type WithId<T> = T & { id: number };
+
+        const user: WithId<{ name: string }> = { name: "a", id: 1 };
+
+        function getId<T>(x: T & { id: number }): number {
+            return x.id;
+        }
+
+        getId(user) satisfies number;
+    "#
+);
+
+fail!(
+	property_missing_on_intersection,
+	r#"
+        function f<T>(x: T & { id: number }) {
+            x.name;
+        }
+    "#,
+	&["Property 'name' does not exist on type 'T & {id: number}'."]
+);
@@ -55,6 +55,72 @@ fail!(
 	&["Type 'number' is not assignable to type '42'."]
 );
 
+pass!(
+	number_literal_float,
+	r#"
+        const x = 1.5;
+        x satisfies 1.5;
+        const y: -0.25 = -0.25;
+        y satisfies number;
+    "#
+);
+
+fail!(
+	number_literal_float_mismatch,
+	r#"
+        const x = 1.5;
+        x satisfies 1.25;
+    "#,
+	&["Type '1.5' is not assignable to type '1.25'."]
+);
+
+pass!(
+	number_literal_same_value_spellings,
+	r#"
+        const hex: 255 = 0xff;
+        const exp: 1000 = 1e3;
+        const big: 18446744073709551616 = 18446744073709551616;
+        big satisfies 18446744073709552000;
+    "#
+);
+
+fail!(
+	number_literal_display_like_javascript,
+	r#"
+        const x = 5;
+        x satisfies 1e21;
+        x satisfies -0;
+    "#,
+	&[
+		"Type '5' is not assignable to type '1e+21'.",
+		"Type '5' is not assignable to type '0'.",
+	]
+);
+
+pass!(
+	number_literal_negative_zero_is_zero,
+	r#"
+        const x = -0;
+        x satisfies -0;
+        x satisfies 0;
+
+        const z: 0 = -0;
+        const t: "0" = `${-0}`;
+    "#
+);
+
+fail!(
+	number_literal_nan_infinity_are_number,
+	r#"
+        const a = NaN;
+        const b = Infinity;
+        a satisfies number;
+        b satisfies number;
+        a satisfies 0;
+    "#,
+	&["Type 'number' is not assignable to type '0'."]
+);
+
 // === String Literal Types ===
 
 pass!(
@@ -316,7 +382,7 @@ fail!(
         const statuses = ["pending", "done"];
         statuses satisfies readonly ["pending", "done"];
     "#,
-	&["Type 'string[]' is not assignable to type '[\"pending\", \"done\"]'."]
+	&["Type 'string[]' is not assignable to type 'readonly [\"pending\", \"done\"]'."]
 );
 
 pass!(
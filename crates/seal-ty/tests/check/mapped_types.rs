@@ -0,0 +1,130 @@
+use super::{fail, pass};
+
+pass!(
+	mapped_type_over_keys,
+	r#"
+        type Flags<K extends string> = { [P in K]: boolean };
+
+        const flags: Flags<"bold" | "italic"> = { bold: true, italic: false };
+        flags.bold satisfies boolean;
+    "#
+);
+
+fail!(
+	mapped_type_missing_key,
+	r#"
+        type Flags<K extends string> = { [P in K]: boolean };
+
+        const flags: Flags<"bold" | "italic"> = { bold: true };
+    "#,
+	&["Type '{bold: boolean}' is not assignable to type '{bold: boolean, italic: boolean}'."]
+);
+
+pass!(
+	mapped_type_partial,
+	r#"
+        interface User {
+            id: number;
+            name: string;
+        }
+        type Partial<T> = { [K in keyof T]?: T[K] };
+
+        const empty: Partial<User> = {};
+        const named: Partial<User> = { name: "Alice" };
+        named.name satisfies string | undefined;
+    "#
+);
+
+fail!(
+	mapped_type_required,
+	r#"
+        interface User {
+            id: number;
+            email?: string;
+        }
+        type Required<T> = { [K in keyof T]-?: T[K] };
+
+        const user: Required<User> = { id: 1 };
+    "#,
+	&["Type '{id: number}' is not assignable to type '{email: string, id: number}'."]
+);
+
+fail!(
+	mapped_type_keeps_modifiers,
+	r#"
+        interface User {
+            readonly id: number;
+            email?: string;
+        }
+        type Copy<T> = { [K in keyof T]: T[K] };
+
+        let user: Copy<User> = { id: 1 };
+        user.id = 2;
+    "#,
+	&["Cannot assign to 'id' because it is a read-only property."]
+);
+
+pass!(
+	mapped_type_removes_readonly,
+	r#"
+        interface User {
+            readonly id: number;
+        }
+        type Mutable<T> = { -readonly [K in keyof T]: T[K] };
+
+        let user: Mutable<User> = { id: 1 };
+        user.id = 2;
+    "#
+);
+
+fail!(
+	mapped_type_adds_readonly,
+	r#"
+        type Frozen<T> = { readonly [K in keyof T]: T[K] };
+
+        let point: Frozen<{ x: number }> = { x: 1 };
+        point.x = 2;
+    "#,
+	&["Cannot assign to 'x' because it is a read-only property."]
+);
+
+fail!(
+	mapped_type_key_remapping,
+	r#"
+        type StringFields<T> = { [K in keyof T as T[K] extends string ? K : never]: T[K] };
+
+        const a: StringFields<{ name: string; age: number }> = { name: "Alice" };
+        const b: StringFields<{ name: string; age: number }> = { name: "Alice", age: 42 };
+    "#,
+	&["Type '{age: number, name: \"Alice\"}' is not assignable to type '{name: string}'."]
+);
+
+pass!(
+	mapped_type_key_remapping_keeps_modifiers,
+	r#"
+        type Renamed<T> = { [K in keyof T as K extends "name" ? "alias" : K]: T[K] };
+
+        const a: Renamed<{ name?: string }> = {};
+        const b: Renamed<{ name?: string }> = { alias: "Alice" };
+    "#
+);
+
+fail!(
+	mapped_type_key_remapping_keeps_readonly,
+	r#"
+        type Renamed<T> = { [K in keyof T as K extends "name" ? "alias" : K]: T[K] };
+
+        const a: Renamed<{ readonly name: string }> = { alias: "Alice" };
+        a.alias = "Bob";
+    "#,
+	&["Cannot assign to 'alias' because it is a read-only property."]
+);
+
+pass!(
+	mapped_type_index_signature,
+	r#"
+        type Counts = { [K in string]: number };
+
+        const counts: Counts = { apples: 1, pears: 2 };
+    "#
+);
@@ -5,15 +5,27 @@ use lib::{fail, pass};
 
 mod arrays;
 mod classes;
+mod conditional_types;
 mod control_flow;
+mod destructuring;
+mod enums;
 mod error_handling;
 mod functions;
+mod generics;
+mod interfaces;
+mod intersections;
 mod literals;
 mod loops;
+mod mapped_types;
 mod objects;
 mod operators;
+mod overloads;
+mod parameters;
 mod primitives;
+mod signatures;
+mod spread;
 mod switch_statements;
 mod templates_regex;
+mod type_operators;
 mod unions;
 mod variables;
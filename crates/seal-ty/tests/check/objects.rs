@@ -195,3 +195,306 @@ fail!(
     "#,
 	&["Type 'string' is not assignable to type 'number'."]
 );
+
+// === Optional Properties ===
+
+pass!(
+	optional_property_may_be_omitted,
+	r#"
+        interface Person {
+            name: string;
+            nickname?: string;
+        }
+
+        const a: Person = { name: "a" };
+        const b: Person = { name: "b", nickname: "bb" };
+        const c: Person = { name: "c", nickname: undefined };
+
+        type Options = { verbose?: boolean; level?: number };
+        const options: Options = {};
+    "#
+);
+
+pass!(
+	optional_property_read_includes_undefined,
+	r#"
+        interface Person {
+            name: string;
+            nickname?: string;
+        }
+
+        const a: Person = { name: "a" };
+        a.nickname satisfies string | undefined;
+
+        function level(options: { level?: number }) {
+            options.level satisfies number | undefined;
+        }
+    "#
+);
+
+fail!(
+	optional_property_read_not_narrowed,
+	r#"
+        interface Person {
+            name: string;
+            nickname?: string;
+        }
+
+        const a: Person = { name: "a" };
+        a.nickname satisfies string;
+    "#,
+	&["Type 'string | undefined' is not assignable to type 'string'."]
+);
+
+fail!(
+	optional_property_wrong_type,
+	r#"
+        interface Person {
+            name: string;
+            nickname?: string;
+        }
+
+        const a: Person = { name: "a", nickname: 1 };
+    "#,
+	&["Type '{name: \"a\", nickname: number}' is not assignable to type 'Person'."]
+);
+
+fail!(
+	optional_property_does_not_satisfy_required,
+	r#"
+        let a: { name: string; nickname?: string } = { name: "a" };
+        let b: { name: string; nickname: string } = a;
+    "#,
+	&[
+		"Type '{name: string, nickname?: string}' is not assignable to type '{name: string, nickname: string}'."
+	]
+);
+
+fail!(
+	optional_property_assignment,
+	r#"
+        interface Person {
+            nickname?: string;
+        }
+
+        let p: Person = {};
+        p.nickname = "x";
+        p.nickname = undefined;
+        p.nickname = 1;
+    "#,
+	&["Type '1' is not assignable to type 'string | undefined'."]
+);
+
+// === Index Signatures ===
+
+pass!(
+	index_signature_access,
+	r#"
+        interface Scores {
+            [name: string]: number;
+        }
+
+        const scores: Scores = { alice: 1, bob: 2 };
+        scores.alice satisfies number;
+        scores["bob"] satisfies number;
+        scores.carol = 3;
+        scores["dave"] = 4;
+
+        function get(scores: Scores, key: string): number {
+            return scores[key];
+        }
+    "#
+);
+
+pass!(
+	number_index_signature,
+	r#"
+        type List = { [index: number]: boolean; length: number };
+
+        function first(list: List, i: number) {
+            list[i] satisfies boolean;
+            list[0] satisfies boolean;
+            list.length satisfies number;
+        }
+    "#
+);
+
+fail!(
+	index_signature_assignment_mismatch,
+	r#"
+        const config: { [key: string]: string } = { host: "localhost" };
+        config.port = 8080;
+        config["user"] = true;
+    "#,
+	&[
+		"Type 'number' is not assignable to type 'string'.",
+		"Type 'boolean' is not assignable to type 'string'."
+	]
+);
+
+fail!(
+	object_literal_does_not_satisfy_index_signature,
+	r#"
+        type Dict = { [key: string]: string };
+
+        const d: Dict = { a: "x", b: 1 };
+    "#,
+	&["Type '{a: \"x\", b: number}' is not assignable to type '{[key: string]: string}'."]
+);
+
+fail!(
+	property_not_assignable_to_index_signature,
+	r#"
+        interface Bad {
+            [key: string]: number;
+            name: string;
+        }
+    "#,
+	&["Property 'name' of type 'string' is not assignable to 'string' index type 'number'."]
+);
+
+fail!(
+	only_canonical_numeric_names_use_number_index_signature,
+	r#"
+        interface Names {
+            [key: number]: number;
+            inf: string;
+            nan: string;
+            "1.0": string;
+            "+1": string;
+            ".5": string;
+            "0.5": string;
+        }
+    "#,
+	&["Property '0.5' of type 'string' is not assignable to 'number' index type 'number'."]
+);
+
+fail!(
+	only_canonical_numeric_names_read_number_index_signature,
+	r#"
+        function f(a: { [key: number]: string }) {
+            a["0.5"] satisfies string;
+            a["1.0"];
+            a["+1"];
+            a["inf"];
+        }
+    "#,
+	&[
+		"Property '1.0' does not exist on type '{[key: number]: string}'.",
+		"Property '+1' does not exist on type '{[key: number]: string}'.",
+		"Property 'inf' does not exist on type '{[key: number]: string}'."
+	]
+);
+
+fail!(
+	invalid_index_signature_parameter,
+	r#"
+        type Bad = { [key: boolean]: number };
+    "#,
+	&[
+		"An index signature parameter type must be 'string', 'number', 'symbol', or a template literal type."
+	]
+);
+
+fail!(
+	interface_needs_explicit_index_signature,
+	r#"
+        interface Named {
+            name: string;
+        }
+
+        function f(named: Named, obj: { name: string }) {
+            const a: { [key: string]: string } = obj;
+            const b: { [key: string]: string } = named;
+        }
+    "#,
+	&["Type 'Named' is not assignable to type '{[key: string]: string}'."]
+);
+
+fail!(
+	index_signature_does_not_provide_required_property,
+	r#"
+        function f(record: { [key: string]: number }) {
+            const a: { a?: number } = record;
+            const b: { a: number } = record;
+        }
+    "#,
+	&["Type '{[key: string]: number}' is not assignable to type '{a: number}'."]
+);
+
+fail!(
+	invalid_index_type,
+	r#"
+        function f(obj: { a: number }, key: boolean) {
+            obj[key];
+        }
+    "#,
+	&["Type 'boolean' cannot be used as an index type."]
+);
+
+pass!(
+	array_satisfies_number_index_signature,
+	r#"
+        const a: { [i: number]: string } = ["a", "b"];
+        const b: { [i: number]: string | number; length: number } = ["a", 1];
+    "#
+);
+
+fail!(
+	array_element_not_assignable_to_number_index_signature,
+	r#"
+        const a: { [i: number]: string } = [1];
+    "#,
+	&["Type 'number[]' is not assignable to type '{[key: number]: string}'."]
+);
+
+fail!(
+	array_not_assignable_to_string_index_signature,
+	r#"
+        const a: { [key: string]: string } = ["a"];
+    "#,
+	&["Type 'string[]' is not assignable to type '{[key: string]: string}'."]
+);
+
+// === Readonly Properties ===
+
+fail!(
+	readonly_interface_property,
+	r#"
+        interface Point {
+            readonly x: number;
+            y: number;
+        }
+
+        let p: Point = { x: 1, y: 2 };
+        p.y = 3;
+        p.x = 4;
+    "#,
+	&["Cannot assign to 'x' because it is a read-only property."]
+);
+
+fail!(
+	readonly_type_literal_property,
+	r#"
+        let config: { readonly name: string } = { name: "a" };
+        config.name satisfies string;
+        config["name"] = "b";
+    "#,
+	&["Cannot assign to 'name' because it is a read-only property."]
+);
+
+pass!(
+	readonly_property_satisfies_mutable,
+	r#"
+        let p: { readonly x: number } = { x: 1 };
+        let q: { x: number } = p;
+    "#
+);
+
+fail!(
+	readonly_modifier_on_non_array_type,
+	r#"
+        let s: readonly string = "a";
+    "#,
+	&["'readonly' type modifier is only permitted on array and tuple literal types."]
+);
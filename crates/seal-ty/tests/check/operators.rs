@@ -86,3 +86,31 @@ fail!(
     "#,
 	&["Type 'number' is not assignable to type 'string'."]
 );
+
+pass!(
+	bigint_arithmetic,
+	r#"
+        function total(price: bigint, quantity: bigint, discount: bigint): bigint {
+            return price * quantity - discount / 2n + 1n;
+        }
+
+        const negated = -total(10n, 3n, 4n);
+        negated satisfies bigint;
+        const affordable = total(10n, 3n, 4n) < 100;
+        affordable satisfies boolean;
+    "#
+);
+
+fail!(
+	bigint_number_arithmetic_mismatch,
+	r#"
+        let cents: bigint = 100n;
+        let rate = 2;
+        let a = cents * rate;
+        let b = rate + cents;
+    "#,
+	&[
+		"Operator '*' cannot be applied to types 'bigint' and 'number'.",
+		"Operator '+' cannot be applied to types 'number' and 'bigint'.",
+	]
+);
@@ -0,0 +1,218 @@
+use super::{fail, pass};
+
+// === Overload Resolution ===
+
+pass!(
+	overloads_resolve_in_order,
+	r#"
+        function parse(x: string): number;
+        function parse(x: number): string;
+        function parse(x: string | number): number | string {
+            return x;
+        }
+
+        const n: number = parse("1");
+        const s: string = parse(1);
+    "#
+);
+
+pass!(
+	single_overload_hides_implementation,
+	r#"
+        function id(x: string): string;
+        function id(x: string | number): string | number {
+            return x;
+        }
+
+        id("a") satisfies string;
+    "#
+);
+
+pass!(
+	generic_overload,
+	r#"
+        function first<T>(xs: T[]): T;
+        function first(xs: string): string;
+        function first(xs: string | number[]): string | number {
+            return 1;
+        }
+
+        const n: number = first([1, 2]);
+        const s: string = first("ab");
+    "#
+);
+
+fail!(
+	no_overload_matches_argument,
+	r#"
+        function parse(x: string): number;
+        function parse(x: number): string;
+        function parse(x: string | number): number | string {
+            return x;
+        }
+
+        parse(true);
+    "#,
+	&[
+		"No overload matches this call. Overload 1 of 2, '(x: string): number', gave the following error. Type 'boolean' is not assignable to type 'string'."
+	]
+);
+
+fail!(
+	no_overload_matches_prefers_arity,
+	r#"
+        function pad(x: string): string;
+        function pad(x: number, width: number): string;
+        function pad(x: string | number): string {
+            return "";
+        }
+
+        pad(1, "b");
+    "#,
+	&[
+		"No overload matches this call. Overload 2 of 2, '(x: number, width: number): string', gave the following error. Type 'string' is not assignable to type 'number'."
+	]
+);
+
+fail!(
+	overload_result_is_checked,
+	r#"
+        function parse(x: string): number;
+        function parse(x: number): string;
+        function parse(x: string | number): number | string {
+            return x;
+        }
+
+        const s: string = parse("1");
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+// === Declarations ===
+
+fail!(
+	overload_incompatible_with_implementation,
+	r#"
+        function f(x: string): boolean;
+        function f(x: string): string {
+            return x;
+        }
+    "#,
+	&["This overload signature is not compatible with its implementation signature."]
+);
+
+pass!(
+	overload_returning_void,
+	r#"
+        function k(x: string): void;
+        function k(x: string): number {
+            return 1;
+        }
+    "#
+);
+
+fail!(
+	implementation_returning_void,
+	r#"
+        function g(x: string): string;
+        function g(x: string | number): void {}
+    "#,
+	&["This overload signature is not compatible with its implementation signature."]
+);
+
+fail!(
+	implementation_with_more_params,
+	r#"
+        function f(x: string): string;
+        function f(x: string, y: number): string {
+            return x;
+        }
+    "#,
+	&["This overload signature is not compatible with its implementation signature."]
+);
+
+fail!(
+	missing_implementation,
+	r#"
+        function f(x: string): string;
+        const y = 1;
+    "#,
+	&["Function implementation is missing or not immediately following the declaration."]
+);
+
+fail!(
+	missing_implementation_at_end,
+	r#"
+        function f(x: string): string;
+    "#,
+	&["Function implementation is missing or not immediately following the declaration."]
+);
+
+fail!(
+	missing_implementation_in_block,
+	r#"
+        const c = true;
+        if (c) {
+            function h(a: string): void;
+        }
+        while (c) {
+            function g(a: string): void;
+        }
+        {
+            function k(a: string): void;
+        }
+        function k(a: string): void {}
+    "#,
+	&[
+		"Function implementation is missing or not immediately following the declaration.",
+		"Function implementation is missing or not immediately following the declaration.",
+		"Function implementation is missing or not immediately following the declaration."
+	]
+);
+
+pass!(
+	overloads_in_block,
+	r#"
+        const c = true;
+        if (c) {
+            function h(a: string): string;
+            function h(a: number): number;
+            function h(a: string | number): string | number {
+                return a;
+            }
+
+            h(1);
+        }
+    "#
+);
+
+// === Assignability ===
+
+pass!(
+	overloaded_assignable_to_signature,
+	r#"
+        function parse(x: string): number;
+        function parse(x: number): string;
+        function parse(x: string | number): number | string {
+            return x;
+        }
+
+        const f: (x: number) => string = parse;
+    "#
+);
+
+fail!(
+	overloaded_not_assignable_to_other_signature,
+	r#"
+        function parse(x: string): number;
+        function parse(x: number): string;
+        function parse(x: string | number): number | string {
+            return x;
+        }
+
+        const f: (x: boolean) => string = parse;
+    "#,
+	&[
+		"Type '{(x: string): number; (x: number): string}' is not assignable to type '(x: boolean) => string'."
+	]
+);
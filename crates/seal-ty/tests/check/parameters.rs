@@ -0,0 +1,261 @@
+use super::{fail, pass};
+
+// === Arity ===
+
+fail!(
+	too_many_arguments,
+	r#"
+        function f(x: number): number {
+            return x;
+        }
+
+        f(1, 2);
+    "#,
+	&["Expected 1 arguments, but got 2."]
+);
+
+fail!(
+	too_few_arguments,
+	r#"
+        function f(x: number, y: number): number {
+            return x;
+        }
+
+        f(1);
+    "#,
+	&["Expected 2 arguments, but got 1."]
+);
+
+// === Optional Parameters ===
+
+pass!(
+	optional_param,
+	r#"
+        function f(x: number, y?: string): number {
+            return x;
+        }
+
+        f(1);
+        f(1, "a");
+        f(1, undefined);
+    "#
+);
+
+fail!(
+	optional_param_arity_range,
+	r#"
+        function f(x: number, y?: string): number {
+            return x;
+        }
+
+        f();
+        f(1, "a", true);
+    "#,
+	&[
+		"Expected 1-2 arguments, but got 0.",
+		"Expected 1-2 arguments, but got 3."
+	]
+);
+
+fail!(
+	optional_param_may_be_undefined,
+	r#"
+        function f(x?: number): number {
+            return x;
+        }
+    "#,
+	&["Type 'number | undefined' is not assignable to type 'number'."]
+);
+
+fail!(
+	required_param_after_optional,
+	r#"
+        function f(x?: number, y: number): void {}
+    "#,
+	&["A required parameter cannot follow an optional parameter."]
+);
+
+// === Default Parameters ===
+
+pass!(
+	default_param,
+	r#"
+        function f(x: number, y: number = 1): number {
+            return y;
+        }
+
+        f(1);
+        f(1, 2);
+        f(1, undefined);
+
+        const g = (x: number, label = "n") => label;
+        g(1) satisfies string;
+    "#
+);
+
+fail!(
+	default_param_type_mismatch,
+	r#"
+        function f(x: number = "a"): void {}
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+fail!(
+	default_param_inferred_type,
+	r#"
+        const g = (label = "n") => label;
+        g(1);
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+pass!(
+	default_param_uses_earlier_param,
+	r#"
+        function f(a: number, b = a): number {
+            return b;
+        }
+
+        const g = (x: string, y: string = x) => y;
+        f(1) satisfies number;
+        g("a") satisfies string;
+    "#
+);
+
+fail!(
+	default_param_uses_later_param,
+	r#"
+        function f(a = b, b: number = 1): void {}
+    "#,
+	&["Cannot find name 'b'."]
+);
+
+// === Rest Parameters ===
+
+pass!(
+	rest_param,
+	r#"
+        function sum(label: string, ...values: number[]): number {
+            return 0;
+        }
+
+        sum("a");
+        sum("a", 1, 2, 3);
+    "#
+);
+
+fail!(
+	rest_param_arity,
+	r#"
+        function sum(label: string, ...values: number[]): number {
+            return 0;
+        }
+
+        sum();
+        sum("a", 1, "b");
+    "#,
+	&[
+		"Expected at least 1 arguments, but got 0.",
+		"Type 'string' is not assignable to type 'number'."
+	]
+);
+
+pass!(
+	rest_param_infers_type_args,
+	r#"
+        function first<T>(...values: T[]): T {
+            return values[0];
+        }
+
+        const n: number = first(1, 2);
+    "#
+);
+
+fail!(
+	rest_param_tuple,
+	r#"
+        function f(...args: [string, number]): void {}
+
+        f("a", 1);
+        f("a");
+    "#,
+	&["Expected 2 arguments, but got 1."]
+);
+
+fail!(
+	rest_param_must_be_array,
+	r#"
+        function f(...args: number): void {}
+    "#,
+	&["A rest parameter must be of an array type."]
+);
+
+// === Function Types ===
+
+pass!(
+	fn_type_optional_and_rest,
+	r#"
+        type Log = (message: string, level?: number, ...tags: string[]) => void;
+
+        function log(message: string): void {}
+
+        let f: Log = log;
+        f("a");
+        f("a", 1, "x", "y");
+    "#
+);
+
+pass!(
+	fewer_params_satisfy,
+	r#"
+        const f = (x: number) => x;
+
+        f satisfies (x: number, y: number) => number;
+        f satisfies (x: number, ...rest: number[]) => number;
+    "#
+);
+
+fail!(
+	more_required_params_do_not_satisfy,
+	r#"
+        const f = (x: number, y: number) => x;
+
+        f satisfies (x: number) => number;
+    "#,
+	&["Type '(x: number, y: number) => number' is not assignable to type '(x: number) => number'."]
+);
+
+// === Built-in Methods ===
+
+pass!(
+	builtin_optional_params,
+	r#"
+        const xs = [1, 2];
+        xs.push(3, 4);
+        xs.slice();
+        xs.slice(1);
+        xs.join();
+        xs.sort();
+        xs.sort((x: number, y: number) => x - y);
+        xs.splice(0);
+        xs.splice(0, 1, 5, 6);
+
+        "abc".slice(1);
+        "abc".indexOf("b");
+        (1.5).toFixed();
+    "#
+);
+
+fail!(
+	builtin_method_arg_types,
+	r#"
+        const xs = [1, 2];
+        xs.sort((x: string, y: string) => 0);
+        xs.splice(0, 1, "a");
+    "#,
+	&[
+		"Type '(x: string, y: string) => 0' is not assignable to type '(a: number, b: number) => number'.",
+		"Type 'string' is not assignable to type 'number'.",
+	]
+);
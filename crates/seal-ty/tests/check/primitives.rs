@@ -52,3 +52,40 @@ pass!(
         }
     "#
 );
+
+pass!(
+	bigint_literal_types,
+	r#"
+        const amount = 100n;
+        amount satisfies 100n;
+        amount satisfies bigint;
+
+        let balance = 0n;
+        balance satisfies bigint;
+
+        const debt: -250n = -250n;
+        const huge = 123456789012345678901234567890n;
+        huge satisfies 123456789012345678901234567890n;
+    "#
+);
+
+fail!(
+	bigint_literal_mismatch,
+	r#"
+        const amount = 100n;
+        amount satisfies 101n;
+        amount satisfies number;
+    "#,
+	&[
+		"Type '100n' is not assignable to type '101n'.",
+		"Type 'bigint' is not assignable to type 'number'.",
+	]
+);
+
+fail!(
+	bigint_number_not_assignable,
+	r#"
+        let cents: bigint = 5;
+    "#,
+	&["Type 'number' is not assignable to type 'bigint'."]
+);
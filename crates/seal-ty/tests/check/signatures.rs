@@ -0,0 +1,197 @@
+use super::{fail, pass};
+
+// === Method Signatures ===
+
+pass!(
+	interface_method,
+	r#"
+        interface User {
+            name: string;
+        }
+
+        interface Api {
+            get(id: string): User;
+            count?(): number;
+        }
+
+        let api: Api = { get: (id: string) => ({ name: id }) };
+        api.get("a") satisfies User;
+    "#
+);
+
+fail!(
+	interface_method_wrong_arg,
+	r#"
+        interface Api {
+            get(id: string): number;
+        }
+
+        let api: Api = { get: (id: string) => 1 };
+        api.get(1);
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+fail!(
+	interface_method_missing,
+	r#"
+        interface Api {
+            get(id: string): number;
+        }
+
+        let api: Api = {};
+    "#,
+	&["Type '{}' is not assignable to type 'Api'."]
+);
+
+pass!(
+	method_overload_signatures,
+	r#"
+        type Parser = {
+            parse(input: string): number;
+            parse(input: number): string;
+        };
+
+        function check(p: Parser): void {
+            p.parse("1") satisfies number;
+            p.parse(1) satisfies string;
+        }
+    "#
+);
+
+// === Call Signatures ===
+
+pass!(
+	call_signature,
+	r#"
+        type Format = { (x: number): string };
+
+        function format(x: number): string {
+            return "a";
+        }
+
+        let f: Format = format;
+        f(1) satisfies string;
+    "#
+);
+
+fail!(
+	call_signature_wrong_arg,
+	r#"
+        type Format = { (x: number): string };
+
+        function check(f: Format): void {
+            f("a");
+        }
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+fail!(
+	call_signature_with_missing_property,
+	r#"
+        interface Labeled {
+            (x: number): string;
+            label: string;
+        }
+
+        function format(x: number): string {
+            return "a";
+        }
+
+        let f: Labeled = format;
+    "#,
+	&["Type '(x: number) => string' is not assignable to type 'Labeled'."]
+);
+
+pass!(
+	callable_as_function,
+	r#"
+        interface Format {
+            (x: number): string;
+        }
+
+        function format(x: number): string {
+            return "a";
+        }
+        function run(f: (x: number) => string): void {}
+
+        let f: Format = format;
+        run(f);
+    "#
+);
+
+// === Construct Signatures ===
+
+pass!(
+	construct_signature,
+	r#"
+        interface User {
+            name: string;
+        }
+
+        interface UserCtor {
+            new (name: string): User;
+        }
+
+        class Member {
+            name: string = "a";
+            constructor(name: string) {}
+        }
+
+        let ctor: UserCtor = Member;
+        new ctor("a") satisfies User;
+    "#
+);
+
+fail!(
+	construct_signature_wrong_arg,
+	r#"
+        interface UserCtor {
+            new (name: string): { name: string };
+        }
+
+        function check(ctor: UserCtor): void {
+            new ctor(1);
+        }
+    "#,
+	&["Type 'number' is not assignable to type 'string'."]
+);
+
+fail!(
+	construct_without_signature,
+	r#"
+        type Format = { (x: number): string };
+
+        function check(f: Format): void {
+            new f(1);
+        }
+    "#,
+	&["This expression is not constructable."]
+);
+
+// === Accessor Signatures ===
+
+pass!(
+	accessor_signatures,
+	r#"
+        type Box = {
+            get value(): number;
+            set value(v: number);
+        };
+
+        let box: Box = { value: 1 };
+        box.value = 2;
+    "#
+);
+
+fail!(
+	getter_signature_is_readonly,
+	r#"
+        type Box = { get value(): number };
+
+        let box: Box = { value: 1 };
+        box.value = 2;
+    "#,
+	&["Cannot assign to 'value' because it is a read-only property."]
+);
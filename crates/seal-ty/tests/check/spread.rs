@@ -0,0 +1,253 @@
+use super::{fail, pass};
+
+// === Spread Arguments ===
+
+pass!(
+	spread_array_to_rest_param,
+	r#"
+        function sum(...nums: number[]): number {
+            return 0;
+        }
+
+        const arr = [1, 2, 3];
+        sum(...arr) satisfies number;
+        sum(1, ...arr, 4);
+    "#
+);
+
+pass!(
+	spread_tuple_args,
+	r#"
+        function pair(a: number, b: string): void {}
+        function opt(a: number, ...rest: string[]): void {}
+
+        const t: [number, string] = [1, "a"];
+        pair(...t);
+        opt(...t);
+    "#
+);
+
+fail!(
+	spread_array_to_non_rest_param,
+	r#"
+        function pair(a: number, b: string): void {}
+
+        const arr = [1, 2, 3];
+        pair(...arr);
+    "#,
+	&["A spread argument must either have a tuple type or be passed to a rest parameter."]
+);
+
+fail!(
+	spread_array_wrong_element_type,
+	r#"
+        function sum(...nums: number[]): number {
+            return 0;
+        }
+
+        const strs = ["a"];
+        sum(...strs);
+    "#,
+	&["Type 'string' is not assignable to type 'number'."]
+);
+
+fail!(
+	spread_tuple_too_many_args,
+	r#"
+        function one(a: number): void {}
+
+        const t: [number, string] = [1, "a"];
+        one(...t);
+    "#,
+	&["Expected 1 arguments, but got 2."]
+);
+
+pass!(
+	spread_new_args,
+	r#"
+        class Point {
+            constructor(x: number, y: number) {}
+        }
+
+        const xy: [number, number] = [1, 2];
+        new Point(...xy);
+    "#
+);
+
+// === Array Literals ===
+
+pass!(
+	spread_arrays,
+	r#"
+        const nums = [1, 2];
+        const strs = ["a"];
+        const joined = [...nums, ...strs, true];
+
+        joined satisfies (number | string | boolean)[];
+    "#
+);
+
+pass!(
+	spread_tuples_as_const,
+	r#"
+        const t: [number, string] = [1, "a"];
+        const u = [...t, true] as const;
+
+        u satisfies [number, string, true];
+    "#
+);
+
+pass!(
+	spread_tuple_to_expected_tuple,
+	r#"
+        const t: [number, string] = [1, "a"];
+        const u: [number, string, number] = [...t, 3];
+    "#
+);
+
+fail!(
+	spread_non_array,
+	r#"
+        const arr = [...5];
+    "#,
+	&["Type '5' is not an array type."]
+);
+
+// === Object Literals ===
+
+pass!(
+	spread_object_override,
+	r#"
+        const base = { a: 1, b: "x" };
+        const updated = { ...base, b: 2 };
+
+        updated satisfies { a: number; b: number };
+    "#
+);
+
+pass!(
+	spread_object_optional_keeps_override,
+	r#"
+        const o: { c?: string } = {};
+        const merged = { c: 5, ...o };
+
+        merged satisfies { c: number | string };
+    "#
+);
+
+fail!(
+	spread_object_overridden_type,
+	r#"
+        const base = { a: 1, b: "x" };
+        const updated = { ...base, b: 2 };
+
+        updated satisfies { a: number; b: string };
+    "#,
+	&["Type '{a: 1, b: 2}' is not assignable to type '{a: number, b: string}'."]
+);
+
+pass!(
+	spread_union_in_object,
+	r#"
+        function copy(y: { a: number } | { b: string }): { a: number } | { b: string } {
+            return { ...y };
+        }
+
+        function tag(y: { a: number } | { b: string }) {
+            const tagged = { ...y, tagged: true };
+            tagged satisfies { a: number; tagged: boolean } | { b: string; tagged: boolean };
+        }
+    "#
+);
+
+fail!(
+	spread_union_in_object_distributes,
+	r#"
+        function copy(y: { a: number } | { b: string }) {
+            const copied = { ...y };
+            copied satisfies { a: number };
+        }
+    "#,
+	&["Type '{a: number} | {b: string}' is not assignable to type '{a: number}'."]
+);
+
+pass!(
+	spread_optional_object,
+	r#"
+        function reduce(
+            state: { count: number; label: string },
+            patch: { count?: number } | undefined,
+        ): { count: number; label: string } {
+            return { ...state, ...patch };
+        }
+
+        function reset(state: { count: number; label: string } | null) {
+            const next = { count: 0, label: "", ...state };
+            next satisfies { count: number; label: string };
+        }
+    "#
+);
+
+pass!(
+	spread_type_parameter_with_constraint,
+	r#"
+        function withId<T extends { name: string }>(value: T) {
+            const named = { ...value, id: 1 };
+            named satisfies { name: string; id: number };
+        }
+    "#
+);
+
+fail!(
+	spread_primitive_in_object,
+	r#"
+        const o = { ...5 };
+    "#,
+	&["Spread types may only be created from object types."]
+);
+
+pass!(
+	shorthand_property,
+	r#"
+        const x = 1;
+        const o = { x };
+
+        o satisfies { x: number };
+    "#
+);
+
+pass!(
+	object_methods_and_getters,
+	r#"
+        const o = {
+            n: 1,
+            get g(): number {
+                return 1;
+            },
+            m(a: number): number {
+                return a;
+            },
+        };
+
+        o.m(1) satisfies number;
+        o.g satisfies number;
+    "#
+);
+
+fail!(
+	object_method_assigns_outer_const,
+	r#"
+        const k = 1;
+        let n = 1;
+        const o = {
+            m(): void {
+                k = 2;
+                n = 2;
+            },
+        };
+    "#,
+	&[
+		"Cannot assign to 'k' because it is a constant.",
+		"Type '2' is not assignable to type '1'."
+	]
+);
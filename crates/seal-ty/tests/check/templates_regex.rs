@@ -190,3 +190,111 @@ fail!(
     "#,
 	&["Property 'foo' does not exist on type 'string'."]
 );
+
+// === Template Literal Types ===
+
+pass!(
+	template_expression_of_literals,
+	r#"
+        const name = "world";
+        const count = 3;
+        `hello ${name}` satisfies "hello world";
+        `${count} items` satisfies "3 items";
+    "#
+);
+
+fail!(
+	template_literal_type_with_union,
+	r#"
+        type EventName = "click" | "focus";
+        type Handler = `on${Capitalize<EventName>}`;
+
+        const a: Handler = "onClick";
+        const b: Handler = "onBlur";
+    "#,
+	&["Type '\"onBlur\"' is not assignable to type '\"onClick\" | \"onFocus\"'."]
+);
+
+fail!(
+	template_literal_type_pattern,
+	r#"
+        type Route = `/users/${number}`;
+        type DataAttribute = `data-${string}`;
+
+        const a: Route = "/users/42";
+        const b: Route = "/users/abc";
+        const c: DataAttribute = "data-id";
+        const d: DataAttribute = "aria-id";
+        const e: string = c;
+    "#,
+	&[
+		"Type '\"/users/abc\"' is not assignable to type '`/users/${number}`'.",
+		"Type '\"aria-id\"' is not assignable to type '`data-${string}`'."
+	]
+);
+
+pass!(
+	template_literal_type_number_hole_js_numbers,
+	r#"
+        type Route = `/users/${number}`;
+
+        const a: Route = "/users/1e3";
+        const b: Route = "/users/0x10";
+        const c: Route = "/users/-1.5";
+        const d: Route = "/users/.5";
+        const e: Route = "/users/0b101";
+    "#
+);
+
+fail!(
+	template_literal_type_number_hole_not_finite,
+	r#"
+        type Route = `/users/${number}`;
+
+        const a: Route = "/users/inf";
+        const b: Route = "/users/nan";
+        const c: Route = "/users/Infinity";
+        const d: Route = "/users/1e999";
+        const e: Route = "/users/-0x10";
+    "#,
+	&[
+		"Type '\"/users/inf\"' is not assignable to type '`/users/${number}`'.",
+		"Type '\"/users/nan\"' is not assignable to type '`/users/${number}`'.",
+		"Type '\"/users/Infinity\"' is not assignable to type '`/users/${number}`'.",
+		"Type '\"/users/1e999\"' is not assignable to type '`/users/${number}`'.",
+		"Type '\"/users/-0x10\"' is not assignable to type '`/users/${number}`'."
+	]
+);
+
+fail!(
+	template_literal_type_from_template,
+	r#"
+        function key(id: string) {
+            const a: `id-${string}` = `id-${id}`;
+            const b: `${string}-${string}` = `id-${id}`;
+            const c: `id-${string}` = `key-${id}`;
+        }
+    "#,
+	&["Type '`key-${string}`' is not assignable to type '`id-${string}`'."]
+);
+
+pass!(
+	template_literal_type_generic,
+	r#"
+        type Key<T extends string> = `${T}_key`;
+
+        const a: Key<"a" | "b"> = "b_key";
+        const b: Uppercase<"abc"> = "ABC";
+    "#
+);
+
+fail!(
+	template_literal_type_infer,
+	r#"
+        type EventOf<T> = T extends `on${infer E}` ? Uncapitalize<E> : never;
+
+        const a: EventOf<"onClick"> = "click";
+        const b: EventOf<"onClick"> = "Click";
+    "#,
+	&["Type '\"Click\"' is not assignable to type '\"click\"'."]
+);
@@ -0,0 +1,171 @@
+use super::{fail, pass};
+
+// === keyof ===
+
+pass!(
+	keyof_interface,
+	r#"
+        interface Person {
+            name: string;
+            age: number;
+        }
+
+        type Key = keyof Person;
+        const key: Key = "age";
+        key satisfies "name" | "age";
+    "#
+);
+
+fail!(
+	keyof_interface_unknown_key,
+	r#"
+        interface Person {
+            name: string;
+            age: number;
+        }
+
+        const key: keyof Person = "email";
+    "#,
+	&["Type '\"email\"' is not assignable to type '\"age\" | \"name\"'."]
+);
+
+pass!(
+	keyof_index_signature,
+	r#"
+        type Scores = { [key: string]: number };
+        const a: keyof Scores = "math";
+        const b: keyof Scores = 1;
+    "#
+);
+
+pass!(
+	keyof_union_keeps_common_keys,
+	r#"
+        type A = { kind: string; a: number };
+        type B = { kind: string; b: number };
+        const key: keyof (A | B) = "kind";
+    "#
+);
+
+// === typeof ===
+
+pass!(
+	typeof_binding,
+	r#"
+        const config: { port: number; host: string } = { port: 8080, host: "localhost" };
+        const port: typeof config.port = 8080;
+        const count = 3;
+        const three: typeof count = 3;
+    "#
+);
+
+fail!(
+	typeof_unknown_binding,
+	r#"
+        type T = typeof missing;
+    "#,
+	&["Cannot find name 'missing'."]
+);
+
+// === Indexed Access ===
+
+pass!(
+	indexed_access_property,
+	r#"
+        interface Person {
+            name: string;
+            age: number;
+        }
+
+        const age: Person["age"] = 42;
+        const value: Person["name" | "age"] = "Alice";
+    "#
+);
+
+fail!(
+	indexed_access_missing_property,
+	r#"
+        interface Person {
+            name: string;
+        }
+
+        type Age = Person["age"];
+        type ByString = Person[string];
+    "#,
+	&[
+		"Property 'age' does not exist on type 'Person'.",
+		"Type 'Person' has no matching index signature for type 'string'."
+	]
+);
+
+pass!(
+	indexed_access_elements,
+	r#"
+        type Pair = [string, number];
+        const first: Pair[0] = "a";
+        const any: Pair[number] = 1;
+        const item: boolean[][number] = true;
+    "#
+);
+
+fail!(
+	indexed_access_tuple_out_of_bounds,
+	r#"
+        type Pair = [string, number];
+        type Third = Pair[2];
+    "#,
+	&["Tuple type '[string, number]' of length '2' has no element at index '2'."]
+);
+
+// === Deriving Types From Constants ===
+
+pass!(
+	types_from_const_object,
+	r#"
+        const Colors = { red: "crimson", green: "lime" } as const;
+        type ColorName = keyof typeof Colors;
+        type ColorValue = (typeof Colors)[ColorName];
+
+        const name: ColorName = "green";
+        const value: ColorValue = "crimson";
+    "#
+);
+
+fail!(
+	types_from_const_array,
+	r#"
+        const roles = ["admin", "user"] as const;
+        type Role = (typeof roles)[number];
+
+        const role: Role = "user";
+        const guest: Role = "guest";
+    "#,
+	&["Type '\"guest\"' is not assignable to type '\"admin\" | \"user\"'."]
+);
+
+// === Generic Property Access ===
+
+pass!(
+	generic_property_getter,
+	r#"
+        function get<T, K extends keyof T>(obj: T, key: K): T[K] {
+            return obj[key];
+        }
+
+        const person = { name: "Alice", age: 42 };
+        get(person, "name") satisfies string;
+        get(person, "age") satisfies number;
+    "#
+);
+
+fail!(
+	generic_property_getter_unknown_key,
+	r#"
+        function get<T, K extends keyof T>(obj: T, key: K): T[K] {
+            return obj[key];
+        }
+
+        get({ name: "Alice" }, "age");
+    "#,
+	&["Type '\"age\"' is not assignable to type '\"name\"'."]
+);